@@ -0,0 +1,53 @@
+//! Configuration for the Market Maker strategy.
+
+use bot_core::{Environment, Market, StrategyId};
+use rust_decimal::Decimal;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct MmConfig {
+    pub strategy_id: StrategyId,
+    pub environment: Environment,
+    pub market: Market,
+
+    /// Distance of each quote from mid, in basis points
+    pub half_spread_bps: Decimal,
+    /// Quote size per side in base asset
+    pub order_size: Decimal,
+    /// Max absolute inventory in base asset
+    pub max_inventory: Decimal,
+    /// Quote shift at full inventory, in basis points (0 = no skew)
+    pub skew_bps: Decimal,
+    /// Requote when mid moves more than this from the quoted mid, in basis points
+    pub requote_threshold_bps: Decimal,
+    /// Minimum time between requotes
+    pub min_requote_interval_ms: i64,
+    /// Refresh quotes older than this (0 = never)
+    pub max_order_age_ms: i64,
+}
+
+impl MmConfig {
+    pub fn validate(&self) -> Vec<String> {
+        let mut errors = Vec::new();
+        if self.half_spread_bps <= Decimal::ZERO {
+            errors.push("half_spread_bps must be > 0".into());
+        }
+        if self.order_size <= Decimal::ZERO {
+            errors.push("order_size must be > 0".into());
+        }
+        if self.max_inventory < self.order_size {
+            errors.push("max_inventory must be >= order_size".into());
+        }
+        if self.skew_bps < Decimal::ZERO {
+            errors.push("skew_bps must be >= 0".into());
+        }
+        if self.requote_threshold_bps < Decimal::ZERO {
+            errors.push("requote_threshold_bps must be >= 0".into());
+        }
+        if self.min_requote_interval_ms < 0 || self.max_order_age_ms < 0 {
+            errors.push("intervals must be >= 0".into());
+        }
+        errors
+    }
+}
@@ -0,0 +1,19 @@
+//! Market Maker Strategy
+//!
+//! A two-sided quoting example that demonstrates:
+//! - Bid/ask placed `half_spread_bps` around mid, post-only, in one batch
+//! - Inventory skew: both quotes shift away from the side that grows inventory
+//! - Inventory cap: the side that would breach `max_inventory` stops quoting.
+//!   The unfilled rest of the quote being replaced counts against the cap,
+//!   since it can fill before its cancel lands
+//! - Requote throttling: only when mid moves past a threshold, at most once
+//!   per `min_requote_interval_ms`
+//! - Order aging: quotes older than `max_order_age_ms` are refreshed
+
+mod config;
+mod state;
+mod strategy;
+
+pub use config::*;
+pub use state::*;
+pub use strategy::*;
@@ -0,0 +1,45 @@
+//! Runtime state for the Market Maker strategy.
+
+use bot_core::{ClientOrderId, Price};
+use rust_decimal::Decimal;
+
+/// One resting quote.
+#[derive(Debug, Clone)]
+pub struct RestingQuote {
+    pub client_id: ClientOrderId,
+    pub price: Price,
+    pub placed_at: i64,
+}
+
+pub struct MmState {
+    pub bid: Option<RestingQuote>,
+    pub ask: Option<RestingQuote>,
+    /// Signed inventory from our own fills (positive = long)
+    pub inventory: Decimal,
+    /// Mid and inventory the current quotes were computed from
+    pub quoted_mid: Option<Price>,
+    pub quoted_inventory: Decimal,
+    pub last_requote_ms: i64,
+    /// Exchange reported Halted — no quoting until Active again
+    pub halted: bool,
+}
+
+impl MmState {
+    pub fn new() -> Self {
+        Self {
+            bid: None,
+            ask: None,
+            inventory: Decimal::ZERO,
+            quoted_mid: None,
+            quoted_inventory: Decimal::ZERO,
+            last_requote_ms: 0,
+            halted: false,
+        }
+    }
+}
+
+impl Default for MmState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
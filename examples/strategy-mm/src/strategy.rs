@@ -0,0 +1,286 @@
+//! Market Maker strategy implementation.
+//!
+//! Each requote cancels the resting bid/ask and places a fresh pair in one
+//! batch. Quotes are centered on mid, shifted by inventory skew:
+//!
+//!   center = mid * (1 - skew_bps * inventory / max_inventory / 10_000)
+//!   bid    = center * (1 - half_spread_bps / 10_000)
+//!   ask    = center * (1 + half_spread_bps / 10_000)
+
+use crate::config::MmConfig;
+use crate::state::{MmState, RestingQuote};
+use bot_core::*;
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use std::time::Duration;
+
+const BPS: Decimal = dec!(10000);
+
+pub struct MmStrategy {
+    config: MmConfig,
+    state: MmState,
+    meta: Option<InstrumentMeta>,
+}
+
+impl MmStrategy {
+    pub fn new(config: MmConfig) -> Self {
+        Self {
+            config,
+            state: MmState::new(),
+            meta: None,
+        }
+    }
+
+    fn exchange(&self) -> ExchangeInstance {
+        self.config
+            .market
+            .exchange_instance(self.config.environment)
+    }
+
+    fn instrument(&self) -> InstrumentId {
+        self.config.market.instrument_id()
+    }
+
+    /// Requote when nothing is quoted, a fill moved inventory, or mid moved
+    /// past the threshold — but never faster than `min_requote_interval_ms`.
+    fn needs_requote(&self, mid: Price, now: i64) -> bool {
+        if now - self.state.last_requote_ms < self.config.min_requote_interval_ms {
+            return false;
+        }
+        let quoted_mid = match self.state.quoted_mid {
+            Some(p) => p,
+            None => return true,
+        };
+        if self.state.inventory != self.state.quoted_inventory {
+            return true;
+        }
+        let moved_bps = ((mid.0 - quoted_mid.0) / quoted_mid.0).abs() * BPS;
+        moved_bps > self.config.requote_threshold_bps
+    }
+
+    /// Unfilled quantity of a quote that can still fill. Its cancel goes out
+    /// in the same step as the replacement, so it may fill first.
+    fn unfilled(ctx: &dyn StrategyContext, quote: &Option<RestingQuote>) -> Decimal {
+        quote
+            .as_ref()
+            .and_then(|q| ctx.order(&q.client_id))
+            .filter(|o| {
+                matches!(
+                    o.status,
+                    OrderStatus::New | OrderStatus::Accepted | OrderStatus::PartiallyFilled
+                )
+            })
+            .map(|o| o.requested_qty.0 - o.filled_qty.0)
+            .unwrap_or(Decimal::ZERO)
+    }
+
+    fn requote(&mut self, ctx: &mut dyn StrategyContext, mid: Price) {
+        let meta = self.meta.as_ref().unwrap();
+        let inventory = self.state.inventory;
+
+        // Long inventory shifts both quotes down: the ask gets hit more often
+        // and the bid less, pulling inventory back towards zero.
+        let fill_ratio = (inventory / self.config.max_inventory).clamp(-Decimal::ONE, Decimal::ONE);
+        let center = mid.0 * (Decimal::ONE - self.config.skew_bps * fill_ratio / BPS);
+        let half_spread = self.config.half_spread_bps / BPS;
+        let bid_px = meta.round_price(Price::new(center * (Decimal::ONE - half_spread)));
+        let ask_px = meta.round_price(Price::new(center * (Decimal::ONE + half_spread)));
+        if bid_px.0 >= ask_px.0 {
+            ctx.log_warn(&format!(
+                "Spread collapsed after rounding (bid={} ask={}) — widen half_spread_bps",
+                bid_px, ask_px
+            ));
+            return;
+        }
+
+        // Room left before hitting the inventory cap on each side, less
+        // whatever the quote being replaced could still fill before its
+        // cancel lands. Spot can't go short, so the ask is limited to what
+        // we hold.
+        let bid_room = self.config.max_inventory - inventory - Self::unfilled(ctx, &self.state.bid);
+        let ask_resting = Self::unfilled(ctx, &self.state.ask);
+        let ask_room = if self.config.market.is_spot() {
+            inventory - ask_resting
+        } else {
+            self.config.max_inventory + inventory - ask_resting
+        };
+        let bid_qty = meta.trunc_qty(Qty::new(self.config.order_size.min(bid_room)));
+        let ask_qty = meta.trunc_qty(Qty::new(self.config.order_size.min(ask_room)));
+
+        // No amend API yet — cancel and replace.
+        for quote in [self.state.bid.take(), self.state.ask.take()]
+            .into_iter()
+            .flatten()
+        {
+            ctx.cancel_order(CancelOrder::new(self.exchange(), quote.client_id));
+        }
+
+        let now = ctx.now_ms();
+        let mut orders = Vec::new();
+        if bid_qty.0 > Decimal::ZERO {
+            let order = PlaceOrder::limit(
+                self.exchange(),
+                self.instrument(),
+                OrderSide::Buy,
+                bid_px,
+                bid_qty,
+            )
+            .post_only();
+            self.state.bid = Some(RestingQuote {
+                client_id: order.client_id.clone(),
+                price: bid_px,
+                placed_at: now,
+            });
+            orders.push(order);
+        }
+        if ask_qty.0 > Decimal::ZERO {
+            let order = PlaceOrder::limit(
+                self.exchange(),
+                self.instrument(),
+                OrderSide::Sell,
+                ask_px,
+                ask_qty,
+            )
+            .post_only();
+            self.state.ask = Some(RestingQuote {
+                client_id: order.client_id.clone(),
+                price: ask_px,
+                placed_at: now,
+            });
+            orders.push(order);
+        }
+        if !orders.is_empty() {
+            ctx.place_orders(orders);
+        }
+
+        self.state.quoted_mid = Some(mid);
+        self.state.quoted_inventory = inventory;
+        self.state.last_requote_ms = now;
+        ctx.log_debug(&format!(
+            "Requoted mid={} inv={} bid={}x{} ask={}x{}",
+            mid, inventory, bid_px, bid_qty, ask_px, ask_qty
+        ));
+    }
+
+    /// Forget a quote that reached a terminal state. If it ended without a
+    /// fill (canceled/rejected), force a requote on the next tick.
+    fn clear_quote(&mut self, client_id: &ClientOrderId, filled: bool) {
+        let is = |q: &Option<RestingQuote>| q.as_ref().map(|q| &q.client_id) == Some(client_id);
+        if is(&self.state.bid) {
+            self.state.bid = None;
+        } else if is(&self.state.ask) {
+            self.state.ask = None;
+        } else {
+            // A quote we already replaced
+            return;
+        }
+        if !filled {
+            self.state.quoted_mid = None;
+        }
+    }
+
+    fn pull_quotes(&mut self, ctx: &mut dyn StrategyContext) {
+        ctx.cancel_all(CancelAll::for_instrument(
+            self.exchange(),
+            self.instrument(),
+        ));
+        self.state.bid = None;
+        self.state.ask = None;
+        self.state.quoted_mid = None;
+    }
+}
+
+impl Strategy for MmStrategy {
+    fn id(&self) -> &StrategyId {
+        &self.config.strategy_id
+    }
+
+    fn on_start(&mut self, ctx: &mut dyn StrategyContext) {
+        self.meta = ctx.instrument_meta(&self.instrument()).cloned();
+        if self.meta.is_none() {
+            ctx.stop_strategy(self.config.strategy_id.clone(), "Instrument not found");
+            return;
+        }
+        let errors = self.config.validate();
+        if !errors.is_empty() {
+            ctx.stop_strategy(self.config.strategy_id.clone(), &errors.join("; "));
+            return;
+        }
+        if self.config.max_order_age_ms > 0 {
+            ctx.set_interval(Duration::from_secs(1));
+        }
+        ctx.log_info(&format!(
+            "MmStrategy started: ±{}bps qty={} max_inv={} skew={}bps",
+            self.config.half_spread_bps,
+            self.config.order_size,
+            self.config.max_inventory,
+            self.config.skew_bps
+        ));
+    }
+
+    fn on_event(&mut self, ctx: &mut dyn StrategyContext, event: &Event) {
+        if self.meta.is_none() {
+            return;
+        }
+        match event {
+            Event::Quote(q) => {
+                if !self.state.halted && self.needs_requote(q.mid(), ctx.now_ms()) {
+                    self.requote(ctx, q.mid());
+                }
+            }
+            Event::OrderFilled(f) => {
+                match f.side {
+                    OrderSide::Buy => self.state.inventory += f.net_qty.0,
+                    OrderSide::Sell => self.state.inventory -= f.qty.0,
+                }
+                ctx.log_info(&format!(
+                    "{} filled {} @ {} — inventory {}",
+                    f.side, f.qty, f.price, self.state.inventory
+                ));
+            }
+            Event::OrderCompleted(c) => self.clear_quote(&c.client_id, true),
+            Event::OrderCanceled(c) => self.clear_quote(&c.client_id, false),
+            Event::OrderRejected(r) => {
+                // Post-only quotes are rejected when the book moved through them
+                ctx.log_debug(&format!("Quote rejected: {}", r.reason));
+                self.clear_quote(&r.client_id, false);
+            }
+            Event::ExchangeStateChanged(e) => {
+                self.state.halted = matches!(e.new_state, ExchangeHealth::Halted);
+                if self.state.halted {
+                    ctx.log_warn(&format!("Exchange halted ({}) — pulling quotes", e.reason));
+                    self.pull_quotes(ctx);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn on_timer(&mut self, ctx: &mut dyn StrategyContext, _timer_id: TimerId) {
+        if self.state.halted {
+            return;
+        }
+        let now = ctx.now_ms();
+        let oldest = [&self.state.bid, &self.state.ask]
+            .into_iter()
+            .flatten()
+            .map(|q| q.placed_at)
+            .min();
+        if let Some(placed_at) = oldest {
+            if now - placed_at >= self.config.max_order_age_ms {
+                if let Some(mid) = ctx.mid_price(&self.instrument()) {
+                    ctx.log_debug("Refreshing aged quotes");
+                    self.requote(ctx, mid);
+                }
+            }
+        }
+    }
+
+    fn on_stop(&mut self, ctx: &mut dyn StrategyContext) {
+        ctx.cancel_all(CancelAll::new(self.exchange()));
+        ctx.log_info(&format!(
+            "MmStrategy stopped — inventory {}",
+            self.state.inventory
+        ));
+    }
+}
@@ -0,0 +1,79 @@
+//! Configuration for the Momentum strategy.
+//!
+//! Strategy-only params: `market` and `environment` come from the top-level
+//! `BotConfig` and are passed to `MomentumStrategy::new`
+//! (see references/indicator-strategies.md, "Config Pattern").
+
+use bot_core::{Market, StrategyId};
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct MomentumConfig {
+    pub strategy_id: StrategyId,
+
+    /// Order quantity in base asset
+    pub order_size: Decimal,
+    /// Bar duration in seconds
+    #[serde(default = "default_bar_interval_secs")]
+    pub bar_interval_secs: u64,
+    /// Fast EMA period in bars
+    pub fast_period: usize,
+    /// Slow EMA period in bars
+    pub slow_period: usize,
+    /// ATR period in bars
+    #[serde(default = "default_atr_period")]
+    pub atr_period: usize,
+    /// Stop distance as a multiple of ATR
+    pub atr_stop_mult: Decimal,
+    /// Take short positions on downward crossovers (perps only)
+    #[serde(default)]
+    pub allow_short: bool,
+    /// How far past the touch an IOC order may cross (0.005 = 0.5%)
+    #[serde(default = "default_max_slippage_pct")]
+    pub max_slippage_pct: Decimal,
+}
+
+fn default_bar_interval_secs() -> u64 {
+    60
+}
+
+fn default_atr_period() -> usize {
+    14
+}
+
+fn default_max_slippage_pct() -> Decimal {
+    dec!(0.005)
+}
+
+impl MomentumConfig {
+    /// `market` is the bot's primary market, which decides whether shorts
+    /// are possible.
+    pub fn validate(&self, market: &Market) -> Vec<String> {
+        let mut errors = Vec::new();
+        if self.order_size <= Decimal::ZERO {
+            errors.push("order_size must be > 0".into());
+        }
+        if self.bar_interval_secs == 0 {
+            errors.push("bar_interval_secs must be > 0".into());
+        }
+        if self.fast_period == 0 || self.fast_period >= self.slow_period {
+            errors.push("fast_period must be > 0 and < slow_period".into());
+        }
+        if self.atr_period == 0 {
+            errors.push("atr_period must be > 0".into());
+        }
+        if self.atr_stop_mult <= Decimal::ZERO {
+            errors.push("atr_stop_mult must be > 0".into());
+        }
+        if self.max_slippage_pct < Decimal::ZERO {
+            errors.push("max_slippage_pct must be >= 0".into());
+        }
+        if self.allow_short && market.is_spot() {
+            errors.push("allow_short requires a perp market".into());
+        }
+        errors
+    }
+}
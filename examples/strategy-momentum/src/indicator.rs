@@ -0,0 +1,143 @@
+//! Trend and volatility indicators.
+//!
+//! Pure `f64` math with no `bot_core` dependency.
+
+/// Exponential moving average, seeded with the SMA of the first `period` closes.
+pub struct Ema {
+    period: usize,
+    alpha: f64,
+    seed_sum: f64,
+    seen: usize,
+    value: Option<f64>,
+}
+
+impl Ema {
+    pub fn new(period: usize) -> Self {
+        Self {
+            period,
+            alpha: 2.0 / (period as f64 + 1.0),
+            seed_sum: 0.0,
+            seen: 0,
+            value: None,
+        }
+    }
+
+    /// Feed one bar close. Returns `None` until `period` closes are seen.
+    pub fn update(&mut self, close: f64) -> Option<f64> {
+        match self.value {
+            Some(prev) => {
+                self.value = Some(prev + self.alpha * (close - prev));
+            }
+            None => {
+                self.seed_sum += close;
+                self.seen += 1;
+                if self.seen == self.period {
+                    self.value = Some(self.seed_sum / self.period as f64);
+                }
+            }
+        }
+        self.value
+    }
+}
+
+/// Wilder's Average True Range.
+pub struct Atr {
+    period: usize,
+    prev_close: Option<f64>,
+    seed_sum: f64,
+    seen: usize,
+    value: Option<f64>,
+}
+
+impl Atr {
+    pub fn new(period: usize) -> Self {
+        Self {
+            period,
+            prev_close: None,
+            seed_sum: 0.0,
+            seen: 0,
+            value: None,
+        }
+    }
+
+    /// Feed one bar. Returns `None` until `period` true ranges are seen.
+    pub fn update(&mut self, high: f64, low: f64, close: f64) -> Option<f64> {
+        let true_range = match self.prev_close {
+            Some(prev) => (high - low)
+                .max((high - prev).abs())
+                .max((low - prev).abs()),
+            None => high - low,
+        };
+        self.prev_close = Some(close);
+
+        match self.value {
+            Some(prev) => {
+                let n = self.period as f64;
+                self.value = Some((prev * (n - 1.0) + true_range) / n);
+            }
+            None => {
+                self.seed_sum += true_range;
+                self.seen += 1;
+                if self.seen == self.period {
+                    self.value = Some(self.seed_sum / self.period as f64);
+                }
+            }
+        }
+        self.value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(actual: Option<f64>, expected: f64) {
+        let actual = actual.expect("indicator not ready");
+        assert!(
+            (actual - expected).abs() < 1e-9,
+            "expected {}, got {}",
+            expected,
+            actual
+        );
+    }
+
+    #[test]
+    fn ema_seeds_with_sma_then_smooths() {
+        let mut ema = Ema::new(3);
+        assert_eq!(ema.update(1.0), None);
+        assert_eq!(ema.update(2.0), None);
+        assert_close(ema.update(3.0), 2.0);
+        // alpha = 2 / (3 + 1) = 0.5
+        assert_close(ema.update(6.0), 4.0);
+        assert_close(ema.update(4.0), 4.0);
+    }
+
+    #[test]
+    fn atr_seeds_with_mean_true_range() {
+        let mut atr = Atr::new(3);
+        assert_eq!(atr.update(10.0, 8.0, 9.0), None); // TR 2 (no prev close)
+        assert_eq!(atr.update(11.0, 9.0, 10.0), None); // TR 2
+        assert_close(atr.update(12.0, 9.0, 11.0), 7.0 / 3.0); // TR 3
+    }
+
+    #[test]
+    fn atr_applies_wilder_smoothing() {
+        let mut atr = Atr::new(3);
+        atr.update(10.0, 8.0, 9.0);
+        atr.update(11.0, 9.0, 10.0);
+        atr.update(12.0, 9.0, 11.0);
+        // TR 2: (7/3 * 2 + 2) / 3
+        assert_close(atr.update(12.0, 10.0, 11.0), 20.0 / 9.0);
+    }
+
+    #[test]
+    fn atr_true_range_covers_gaps() {
+        // Period 1 makes the ATR equal to the latest true range
+        let mut atr = Atr::new(1);
+        assert_close(atr.update(11.0, 10.0, 11.0), 1.0);
+        // Gap up: prev close 11 below the bar's low
+        assert_close(atr.update(15.0, 14.0, 14.5), 4.0);
+        // Gap down: prev close 14.5 above the bar's high
+        assert_close(atr.update(10.0, 9.0, 9.5), 5.5);
+    }
+}
@@ -0,0 +1,22 @@
+//! Momentum / Trend-Following Strategy
+//!
+//! An indicator strategy built on the 3-layer pattern
+//! (see references/indicator-strategies.md):
+//! - Quotes are aggregated into bars (`BarBuilder`)
+//! - Fast/slow EMA crossover picks the direction, ATR sizes the stop
+//! - One directional position at a time, entered and exited with IOC orders
+//! - The stop trails by `atr_stop_mult` × ATR on every bar close and is
+//!   checked on every quote
+//! - An opposite crossover closes the position, and with `allow_short` flips it
+
+mod bar;
+mod config;
+mod indicator;
+mod state;
+mod strategy;
+
+pub use bar::*;
+pub use config::*;
+pub use indicator::*;
+pub use state::*;
+pub use strategy::*;
@@ -0,0 +1,66 @@
+//! Runtime state for the Momentum strategy.
+
+use bot_core::{ClientOrderId, OrderSide, Price};
+use rust_decimal::Decimal;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Phase {
+    /// Collecting bars until all indicators converge
+    WarmingUp,
+    /// No position, waiting for a crossover
+    Flat,
+    /// Entry order in flight
+    Opening,
+    /// Position held, stop armed
+    InPosition,
+    /// Exit order in flight
+    Closing,
+}
+
+pub struct MomentumState {
+    pub phase: Phase,
+    /// Latest (bid, ask) — IOC orders price off the touch
+    pub last_bid_ask: Option<(Price, Price)>,
+    /// Fast minus slow EMA on the previous bar, for crossover detection
+    pub prev_spread: Option<f64>,
+    /// ATR as of the last bar close
+    pub last_atr: Option<Decimal>,
+    pub active_order: Option<ClientOrderId>,
+    /// Signed position from our own fills (positive = long, negative = short)
+    pub position_qty: Decimal,
+    /// Gross price * qty and qty over entry fills, for the average entry price
+    pub entry_notional: Decimal,
+    pub entry_qty: Decimal,
+    pub stop_px: Option<Price>,
+    /// Side to open once the current exit completes (reversal)
+    pub pending_entry: Option<OrderSide>,
+    /// An opposite crossover called for an exit — keep exiting until flat
+    pub signal_exit: bool,
+    /// When the last exit order was placed, for retry throttling
+    pub last_exit_ms: i64,
+}
+
+impl MomentumState {
+    pub fn new() -> Self {
+        Self {
+            phase: Phase::WarmingUp,
+            last_bid_ask: None,
+            prev_spread: None,
+            last_atr: None,
+            active_order: None,
+            position_qty: Decimal::ZERO,
+            entry_notional: Decimal::ZERO,
+            entry_qty: Decimal::ZERO,
+            stop_px: None,
+            pending_entry: None,
+            signal_exit: false,
+            last_exit_ms: 0,
+        }
+    }
+}
+
+impl Default for MomentumState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
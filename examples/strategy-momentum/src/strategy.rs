@@ -0,0 +1,460 @@
+//! Momentum strategy implementation.
+//!
+//! Event flow:
+//!   Quote → [BarBuilder] → Bar → fast/slow EMA + ATR → crossover → IOC entry
+//!   Quote → trailing ATR stop check → IOC reduce-only exit
+//!
+//! Phases:
+//!   WarmingUp → Flat → Opening → InPosition → Closing → Flat (or Opening on a flip)
+
+use crate::bar::{Bar, BarBuilder};
+use crate::config::MomentumConfig;
+use crate::indicator::{Atr, Ema};
+use crate::state::{MomentumState, Phase};
+use bot_core::*;
+use rust_decimal::prelude::{FromPrimitive, ToPrimitive};
+use rust_decimal::Decimal;
+
+/// Minimum gap between exit attempts, so an exit that keeps failing
+/// cannot turn into one order per quote.
+const EXIT_RETRY_MS: i64 = 1_000;
+
+/// EMA-crossover trend follower with a trailing ATR stop.
+pub struct MomentumStrategy {
+    config: MomentumConfig,
+    market: Market,
+    environment: Environment,
+    state: MomentumState,
+    instrument_meta: Option<InstrumentMeta>,
+    bar_builder: BarBuilder,
+    fast_ema: Ema,
+    slow_ema: Ema,
+    atr: Atr,
+}
+
+impl MomentumStrategy {
+    /// `market` and `environment` come from the top-level `BotConfig`
+    /// (`config.primary_market()` / `config.parse_environment()`).
+    pub fn new(config: MomentumConfig, market: Market, environment: Environment) -> Self {
+        let bar_builder = BarBuilder::new(config.bar_interval_secs);
+        let fast_ema = Ema::new(config.fast_period);
+        let slow_ema = Ema::new(config.slow_period);
+        let atr = Atr::new(config.atr_period);
+        Self {
+            config,
+            market,
+            environment,
+            state: MomentumState::new(),
+            instrument_meta: None,
+            bar_builder,
+            fast_ema,
+            slow_ema,
+            atr,
+        }
+    }
+
+    /// Round price to tick size and 5 significant figures.
+    fn round_price(&self, price: Price) -> Price {
+        let trimmed = price.trim_to_sig_figs(5);
+        if let Some(ref meta) = self.instrument_meta {
+            meta.round_price(trimmed)
+        } else {
+            trimmed
+        }
+    }
+
+    /// Round quantity to lot size.
+    fn round_qty(&self, qty: Qty) -> Qty {
+        if let Some(ref meta) = self.instrument_meta {
+            meta.round_qty(qty)
+        } else {
+            qty
+        }
+    }
+
+    /// Floor quantity to lot size, so exits never exceed the position.
+    fn trunc_qty(&self, qty: Qty) -> Qty {
+        if let Some(ref meta) = self.instrument_meta {
+            meta.trunc_qty(qty)
+        } else {
+            qty
+        }
+    }
+
+    fn exchange_instance(&self) -> ExchangeInstance {
+        self.market.exchange_instance(self.environment)
+    }
+
+    fn instrument_id(&self) -> InstrumentId {
+        self.market.instrument_id()
+    }
+
+    /// Side of the held position, judged from our own fills.
+    fn position_side(&self) -> Option<OrderSide> {
+        if self.state.position_qty > Decimal::ZERO {
+            Some(OrderSide::Buy)
+        } else if self.state.position_qty < Decimal::ZERO {
+            Some(OrderSide::Sell)
+        } else {
+            None
+        }
+    }
+
+    fn on_bar(&mut self, ctx: &mut dyn StrategyContext, bar: Bar) {
+        let fast = self.fast_ema.update(bar.close);
+        let slow = self.slow_ema.update(bar.close);
+        let atr = self.atr.update(bar.high, bar.low, bar.close);
+        let (fast, slow, atr) = match (fast, slow, atr) {
+            (Some(fast), Some(slow), Some(atr)) => (fast, slow, atr),
+            _ => return,
+        };
+
+        if self.state.phase == Phase::WarmingUp {
+            ctx.log_info(&format!(
+                "Indicators ready: fast={:.4} slow={:.4} atr={:.4}",
+                fast, slow, atr
+            ));
+            self.state.phase = Phase::Flat;
+        }
+
+        let spread = fast - slow;
+        let (crossed_up, crossed_down) = match self.state.prev_spread {
+            Some(prev) => (prev <= 0.0 && spread > 0.0, prev >= 0.0 && spread < 0.0),
+            None => (false, false),
+        };
+        self.state.prev_spread = Some(spread);
+        self.state.last_atr = Decimal::from_f64(atr);
+
+        match self.state.phase {
+            Phase::Flat => {
+                if crossed_up {
+                    ctx.log_info(&format!("Bullish cross: fast={:.4} slow={:.4}", fast, slow));
+                    self.open(ctx, OrderSide::Buy);
+                } else if crossed_down && self.config.allow_short {
+                    ctx.log_info(&format!("Bearish cross: fast={:.4} slow={:.4}", fast, slow));
+                    self.open(ctx, OrderSide::Sell);
+                }
+            }
+            Phase::InPosition => {
+                let side = self.position_side();
+                if side == Some(OrderSide::Buy) && crossed_down {
+                    ctx.log_info("Bearish cross against long — closing");
+                    self.state.pending_entry = self.config.allow_short.then_some(OrderSide::Sell);
+                    self.state.signal_exit = true;
+                    self.close(ctx);
+                } else if side == Some(OrderSide::Sell) && crossed_up {
+                    ctx.log_info("Bullish cross against short — closing");
+                    self.state.pending_entry = Some(OrderSide::Buy);
+                    self.state.signal_exit = true;
+                    self.close(ctx);
+                } else {
+                    self.trail_stop(ctx, bar.close);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Ratchet the stop toward price by `atr_stop_mult` × ATR. It never loosens.
+    fn trail_stop(&mut self, ctx: &mut dyn StrategyContext, close: f64) {
+        let (close, atr) = match (Decimal::from_f64(close), self.state.last_atr) {
+            (Some(close), Some(atr)) => (close, atr),
+            _ => return,
+        };
+        let distance = atr * self.config.atr_stop_mult;
+        let trailed = match (self.position_side(), self.state.stop_px) {
+            (Some(OrderSide::Buy), Some(stop)) => {
+                let candidate = self.round_price(Price::new(close - distance));
+                (candidate.0 > stop.0).then_some(candidate)
+            }
+            (Some(OrderSide::Sell), Some(stop)) => {
+                let candidate = self.round_price(Price::new(close + distance));
+                (candidate.0 < stop.0).then_some(candidate)
+            }
+            _ => None,
+        };
+        if let Some(stop) = trailed {
+            ctx.log_debug(&format!("Stop trailed to {}", stop));
+            self.state.stop_px = Some(stop);
+        }
+    }
+
+    /// Exit when mid crosses the stop.
+    fn check_stop(&mut self, ctx: &mut dyn StrategyContext, mid: Price) {
+        let hit = match (self.position_side(), self.state.stop_px) {
+            (Some(OrderSide::Buy), Some(stop)) => mid.0 <= stop.0,
+            (Some(OrderSide::Sell), Some(stop)) => mid.0 >= stop.0,
+            _ => false,
+        };
+        if hit {
+            ctx.log_info(&format!(
+                "Stop hit @ mid={} stop={:?}",
+                mid, self.state.stop_px
+            ));
+            self.state.pending_entry = None;
+            self.close(ctx);
+        }
+    }
+
+    fn open(&mut self, ctx: &mut dyn StrategyContext, side: OrderSide) {
+        let qty = self.round_qty(Qty::new(self.config.order_size));
+        self.state.entry_notional = Decimal::ZERO;
+        self.state.entry_qty = Decimal::ZERO;
+        if self.place_taker(ctx, side, qty, false) {
+            self.state.phase = Phase::Opening;
+        }
+    }
+
+    fn close(&mut self, ctx: &mut dyn StrategyContext) {
+        let side = match self.position_side() {
+            Some(OrderSide::Buy) => OrderSide::Sell,
+            Some(OrderSide::Sell) => OrderSide::Buy,
+            None => return,
+        };
+        let now = ctx.now_ms();
+        if now - self.state.last_exit_ms < EXIT_RETRY_MS {
+            return;
+        }
+        let qty = self.trunc_qty(Qty::new(self.state.position_qty.abs()));
+        if self.place_taker(ctx, side, qty, true) {
+            self.state.last_exit_ms = now;
+            self.state.phase = Phase::Closing;
+        }
+    }
+
+    /// Re-read the position after an exit was rejected — it may have been
+    /// closed or reduced outside the bot. Spot holdings can include coins
+    /// this strategy never bought, so only the fill-tracked amount counts.
+    fn sync_position(&mut self, ctx: &mut dyn StrategyContext) {
+        let actual = if self.market.is_spot() {
+            let base = &self.instrument_meta.as_ref().unwrap().base_asset;
+            ctx.balance(base).total.min(self.state.position_qty)
+        } else {
+            ctx.position(&self.instrument_id()).qty
+        };
+        if actual != self.state.position_qty {
+            ctx.log_warn(&format!(
+                "Position resynced: tracked {} → exchange {}",
+                self.state.position_qty, actual
+            ));
+            self.state.position_qty = actual;
+        }
+    }
+
+    /// Place a marketable IOC limit crossing the touch by up to
+    /// `max_slippage_pct`. Returns false when there is no quote to price from.
+    fn place_taker(
+        &mut self,
+        ctx: &mut dyn StrategyContext,
+        side: OrderSide,
+        qty: Qty,
+        reduce_only: bool,
+    ) -> bool {
+        let (bid, ask) = match self.state.last_bid_ask {
+            Some(bid_ask) => bid_ask,
+            None => return false,
+        };
+        let raw = match side {
+            OrderSide::Buy => ask.0 * (Decimal::ONE + self.config.max_slippage_pct),
+            OrderSide::Sell => bid.0 * (Decimal::ONE - self.config.max_slippage_pct),
+        };
+        let price = self.round_price(Price::new(raw));
+        let mut order = PlaceOrder::limit(
+            self.exchange_instance(),
+            self.instrument_id(),
+            side,
+            price,
+            qty,
+        )
+        .with_tif(TimeInForce::Ioc);
+        if reduce_only && !self.market.is_spot() {
+            order = order.reduce_only();
+        }
+        self.state.active_order = Some(order.client_id.clone());
+        ctx.place_order(order);
+        ctx.log_info(&format!("{} IOC placed @ {} qty={}", side, price, qty));
+        true
+    }
+
+    /// Our active order reached a terminal state. IOC orders can end
+    /// canceled after a partial fill, so the outcome is judged from the
+    /// tracked position, not the event type.
+    fn on_order_done(&mut self, ctx: &mut dyn StrategyContext, client_id: &ClientOrderId) {
+        if self.state.active_order.as_ref() != Some(client_id) {
+            return;
+        }
+        self.state.active_order = None;
+        match self.state.phase {
+            Phase::Opening => self.on_entry_done(ctx),
+            Phase::Closing => self.on_exit_done(ctx),
+            _ => {}
+        }
+    }
+
+    fn on_entry_done(&mut self, ctx: &mut dyn StrategyContext) {
+        if self.state.entry_qty.is_zero() {
+            ctx.log_warn("Entry not filled — back to flat");
+            self.state.phase = Phase::Flat;
+            return;
+        }
+        let entry = self.state.entry_notional / self.state.entry_qty;
+        let distance = self.state.last_atr.unwrap_or(Decimal::ZERO) * self.config.atr_stop_mult;
+        let stop = match self.position_side() {
+            Some(OrderSide::Sell) => entry + distance,
+            _ => entry - distance,
+        };
+        self.state.stop_px = Some(self.round_price(Price::new(stop)));
+        self.state.phase = Phase::InPosition;
+        ctx.log_info(&format!(
+            "In position: qty={} entry={} stop={:?}",
+            self.state.position_qty, entry, self.state.stop_px
+        ));
+    }
+
+    fn on_exit_done(&mut self, ctx: &mut dyn StrategyContext) {
+        if !self
+            .trunc_qty(Qty::new(self.state.position_qty.abs()))
+            .is_zero()
+        {
+            // At most one retry per EXIT_RETRY_MS: a signal exit retries on
+            // the next quote after that, a stop exit only while mid stays
+            // beyond the stop.
+            ctx.log_warn(&format!(
+                "Exit incomplete, {} left",
+                self.state.position_qty
+            ));
+            self.state.phase = Phase::InPosition;
+            return;
+        }
+        ctx.log_info("Position closed");
+        self.state.stop_px = None;
+        self.state.signal_exit = false;
+        self.state.phase = Phase::Flat;
+        if let Some(side) = self.state.pending_entry.take() {
+            ctx.log_info(&format!("Reversing into {}", side));
+            self.open(ctx, side);
+        }
+    }
+}
+
+impl Strategy for MomentumStrategy {
+    fn id(&self) -> &StrategyId {
+        &self.config.strategy_id
+    }
+
+    fn on_start(&mut self, ctx: &mut dyn StrategyContext) {
+        let instrument = self.instrument_id();
+        self.instrument_meta = ctx.instrument_meta(&instrument).cloned();
+
+        if self.instrument_meta.is_none() {
+            ctx.log_error(&format!("Instrument not found: {}", instrument));
+            ctx.stop_strategy(self.config.strategy_id.clone(), "Instrument not found");
+            return;
+        }
+
+        let errors = self.config.validate(&self.market);
+        if !errors.is_empty() {
+            for err in &errors {
+                ctx.log_error(&format!("Config error: {}", err));
+            }
+            ctx.stop_strategy(
+                self.config.strategy_id.clone(),
+                &format!("Config validation failed: {}", errors.join("; ")),
+            );
+            return;
+        }
+
+        ctx.log_info(&format!(
+            "MomentumStrategy started: {} order_size={} bars={}s ema={}/{} atr={}x{} short={}",
+            instrument,
+            self.config.order_size,
+            self.config.bar_interval_secs,
+            self.config.fast_period,
+            self.config.slow_period,
+            self.config.atr_period,
+            self.config.atr_stop_mult,
+            self.config.allow_short
+        ));
+    }
+
+    fn on_event(&mut self, ctx: &mut dyn StrategyContext, event: &Event) {
+        if self.instrument_meta.is_none() {
+            return;
+        }
+
+        match event {
+            Event::Quote(q) => {
+                self.state.last_bid_ask = Some((q.bid, q.ask));
+                let mid = q.mid();
+
+                // Stops and unfinished signal exits run on every quote, signals
+                // only on bar close
+                if self.state.phase == Phase::InPosition {
+                    if self.state.signal_exit {
+                        self.close(ctx);
+                    } else {
+                        self.check_stop(ctx, mid);
+                    }
+                }
+                let mid_f64 = mid.0.to_f64().unwrap_or(0.0);
+                if let Some(bar) = self.bar_builder.update(mid_f64, q.ts) {
+                    self.on_bar(ctx, bar);
+                }
+            }
+            Event::OrderFilled(f) => {
+                ctx.log_info(&format!(
+                    "Filled: {} {} @ {} qty={}",
+                    f.side, f.client_id, f.price, f.qty
+                ));
+
+                // Spot BUY fees are taken in the base asset — track net_qty.
+                match f.side {
+                    OrderSide::Buy => self.state.position_qty += f.net_qty.0,
+                    OrderSide::Sell => self.state.position_qty -= f.qty.0,
+                }
+                if self.state.phase == Phase::Opening {
+                    self.state.entry_notional += f.price.0 * f.qty.0;
+                    self.state.entry_qty += f.qty.0;
+                }
+            }
+            Event::OrderCompleted(c) => self.on_order_done(ctx, &c.client_id),
+            Event::OrderCanceled(c) => self.on_order_done(ctx, &c.client_id),
+            Event::OrderRejected(r) => {
+                ctx.log_warn(&format!("Rejected: {} reason={}", r.client_id, r.reason));
+                let our_exit = self.state.phase == Phase::Closing
+                    && self.state.active_order.as_ref() == Some(&r.client_id);
+                if our_exit {
+                    // Don't keep re-sending a signal exit the exchange refuses;
+                    // whatever is left stays under the ATR stop.
+                    self.sync_position(ctx);
+                    if self.state.signal_exit {
+                        self.state.signal_exit = false;
+                        self.state.pending_entry = None;
+                    }
+                }
+                self.on_order_done(ctx, &r.client_id);
+            }
+            Event::ExchangeStateChanged(e) => {
+                ctx.log_info(&format!(
+                    "Exchange state: {:?} -> {:?} ({})",
+                    e.old_state, e.new_state, e.reason
+                ));
+            }
+            _ => {}
+        }
+    }
+
+    fn on_timer(&mut self, _ctx: &mut dyn StrategyContext, _timer_id: TimerId) {}
+
+    fn on_stop(&mut self, ctx: &mut dyn StrategyContext) {
+        ctx.cancel_all(CancelAll::new(self.exchange_instance()));
+        if !self.state.position_qty.is_zero() {
+            ctx.log_warn(&format!(
+                "MomentumStrategy stopping with open position: {}",
+                self.state.position_qty
+            ));
+        } else {
+            ctx.log_info("MomentumStrategy stopping");
+        }
+    }
+}
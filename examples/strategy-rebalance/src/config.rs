@@ -0,0 +1,78 @@
+//! Configuration for the Rebalance strategy.
+//!
+//! Strategy-only params: the spot markets and `environment` come from the
+//! top-level `BotConfig` and are passed to `RebalanceStrategy::new`
+//! (see references/indicator-strategies.md, "Config Pattern").
+
+use bot_core::StrategyId;
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct RebalanceConfig {
+    pub strategy_id: StrategyId,
+
+    /// Target share of portfolio value per base asset, e.g.
+    /// `{ "HYPE": "0.5", "UBTC": "0.3" }`. Each needs a spot market in the
+    /// top-level `markets`. Weights may sum to less than 1 — the rest stays
+    /// in the quote asset.
+    pub assets: BTreeMap<String, Decimal>,
+    /// Rebalance an asset once its weight is this far from target
+    /// (0.05 = 5 percentage points)
+    pub drift_band: Decimal,
+    /// Seconds between drift checks
+    #[serde(default = "default_check_interval_secs")]
+    pub check_interval_secs: u64,
+    /// Smallest trade worth placing, in quote asset
+    #[serde(default = "default_min_trade_notional")]
+    pub min_trade_notional: Decimal,
+    /// How far past the touch an IOC order may cross (0.005 = 0.5%)
+    #[serde(default = "default_max_slippage_pct")]
+    pub max_slippage_pct: Decimal,
+}
+
+fn default_check_interval_secs() -> u64 {
+    60
+}
+
+fn default_min_trade_notional() -> Decimal {
+    dec!(10)
+}
+
+fn default_max_slippage_pct() -> Decimal {
+    dec!(0.005)
+}
+
+impl RebalanceConfig {
+    pub fn validate(&self) -> Vec<String> {
+        let mut errors = Vec::new();
+        if self.assets.is_empty() {
+            errors.push("assets must not be empty".into());
+        }
+        for (base, weight) in &self.assets {
+            if *weight <= Decimal::ZERO {
+                errors.push(format!("assets.{} weight must be > 0", base));
+            }
+        }
+        let total: Decimal = self.assets.values().copied().sum();
+        if total > Decimal::ONE {
+            errors.push(format!("asset weights sum to {}, must be <= 1", total));
+        }
+        if self.drift_band <= Decimal::ZERO || self.drift_band >= Decimal::ONE {
+            errors.push("drift_band must be between 0 and 1".into());
+        }
+        if self.check_interval_secs == 0 {
+            errors.push("check_interval_secs must be > 0".into());
+        }
+        if self.min_trade_notional < Decimal::ZERO {
+            errors.push("min_trade_notional must be >= 0".into());
+        }
+        if self.max_slippage_pct < Decimal::ZERO {
+            errors.push("max_slippage_pct must be >= 0".into());
+        }
+        errors
+    }
+}
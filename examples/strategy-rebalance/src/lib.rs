@@ -0,0 +1,44 @@
+//! Portfolio Rebalancer Strategy
+//!
+//! Holds several spot assets at target weights of the portfolio's value,
+//! with the quote asset (e.g. USDC) as the remainder:
+//! - Every `check_interval_secs`, values holdings at mid and compares each
+//!   asset's weight to its target
+//! - Only assets drifted beyond `drift_band` are traded, straight back to
+//!   target; the quote balance absorbs the difference
+//! - Sells go first so their proceeds can fund the buys, and buys are
+//!   scaled down to the available quote balance
+//! - Trades smaller than `min_trade_notional` are skipped
+//!
+//! Markets and environment come from the top-level config (the Config
+//! Pattern in references/indicator-strategies.md); the strategy section
+//! only maps each base asset to its weight. Every asset needs a spot
+//! market in `markets`, and all of them must share one quote asset:
+//!
+//! ```json
+//! {
+//!   "strategy_type": "rebalance",
+//!   "environment": "mainnet",
+//!   "markets": [
+//!     { "exchange": "hyperliquid", "type": "spot", "base": "HYPE", "quote": "USDC", "index": 10107 },
+//!     { "exchange": "hyperliquid", "type": "spot", "base": "UBTC", "quote": "USDC", "index": 10142 }
+//!   ],
+//!   "rebalance": {
+//!     "strategy_id": "hype-btc-rebalance",
+//!     "assets": { "HYPE": "0.5", "UBTC": "0.3" },
+//!     "drift_band": "0.05"
+//!   }
+//! }
+//! ```
+//!
+//! Here the remaining 20% stays in USDC. Look up each spot `index` in
+//! `spotMeta` (references/hl-info-api.md) before deploying. An asset with
+//! no matching spot market stops the strategy on start.
+
+mod config;
+mod state;
+mod strategy;
+
+pub use config::*;
+pub use state::*;
+pub use strategy::*;
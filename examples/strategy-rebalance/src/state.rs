@@ -0,0 +1,39 @@
+//! Runtime state for the Rebalance strategy.
+
+use bot_core::ClientOrderId;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Phase {
+    /// Waiting for the next drift check
+    Idle,
+    /// Sell orders in flight
+    Selling,
+    /// Buy orders in flight
+    Buying,
+}
+
+pub struct RebalanceState {
+    pub phase: Phase,
+    /// IOC orders of the current leg that have not reached a terminal state
+    pub pending: Vec<ClientOrderId>,
+    /// Completed rebalances
+    pub rebalances: u64,
+    pub halted: bool,
+}
+
+impl RebalanceState {
+    pub fn new() -> Self {
+        Self {
+            phase: Phase::Idle,
+            pending: Vec::new(),
+            rebalances: 0,
+            halted: false,
+        }
+    }
+}
+
+impl Default for RebalanceState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,375 @@
+//! Rebalance strategy implementation.
+//!
+//! Cycle (every `check_interval_secs`):
+//!   Idle → value holdings → Selling (overweight assets)
+//!        → Buying (underweight assets, from fresh balances) → Idle
+
+use crate::config::RebalanceConfig;
+use crate::state::{Phase, RebalanceState};
+use bot_core::*;
+use rust_decimal::Decimal;
+use std::time::Duration;
+
+/// A configured asset, resolved against its instrument metadata.
+struct Slot {
+    exchange: ExchangeInstance,
+    instrument: InstrumentId,
+    meta: InstrumentMeta,
+    target: Decimal,
+}
+
+/// A trade needed to bring one slot back to its target weight.
+struct Drift {
+    slot: usize,
+    /// Quote notional to buy (positive) or sell (negative)
+    notional: Decimal,
+    mid: Decimal,
+}
+
+pub struct RebalanceStrategy {
+    config: RebalanceConfig,
+    markets: Vec<Market>,
+    environment: Environment,
+    state: RebalanceState,
+    slots: Vec<Slot>,
+    quote_asset: Option<AssetId>,
+}
+
+impl RebalanceStrategy {
+    /// `markets` and `environment` come from the top-level `BotConfig`.
+    /// Every configured asset must have a spot market among `markets`.
+    pub fn new(config: RebalanceConfig, markets: Vec<Market>, environment: Environment) -> Self {
+        Self {
+            config,
+            markets,
+            environment,
+            state: RebalanceState::new(),
+            slots: Vec::new(),
+            quote_asset: None,
+        }
+    }
+
+    /// Smallest trade worth placing on a slot: the market's `min_notional`
+    /// or `min_trade_notional`, whichever is larger.
+    fn min_notional(&self, slot: &Slot) -> Decimal {
+        slot.meta
+            .min_notional
+            .unwrap_or(Decimal::ZERO)
+            .max(self.config.min_trade_notional)
+    }
+
+    /// Value the portfolio at mid and return the trades for every slot whose
+    /// weight is outside `drift_band`. Fails with the first instrument that
+    /// has no mid price yet.
+    fn drifts(&self, ctx: &dyn StrategyContext) -> Result<Vec<Drift>, InstrumentId> {
+        let quote_asset = match self.quote_asset {
+            Some(ref asset) => asset,
+            None => return Ok(Vec::new()),
+        };
+        let mut mids = Vec::with_capacity(self.slots.len());
+        let mut values = Vec::with_capacity(self.slots.len());
+        for slot in &self.slots {
+            let mid = match ctx.mid_price(&slot.instrument) {
+                Some(mid) => mid.0,
+                None => return Err(slot.instrument.clone()),
+            };
+            let held = ctx.balance(&slot.meta.base_asset).total;
+            mids.push(mid);
+            values.push(held * mid);
+        }
+        let nav = ctx.balance(quote_asset).total + values.iter().copied().sum::<Decimal>();
+        if nav <= Decimal::ZERO {
+            return Ok(Vec::new());
+        }
+
+        let mut drifts = Vec::new();
+        for (i, slot) in self.slots.iter().enumerate() {
+            let weight = values[i] / nav;
+            if (weight - slot.target).abs() <= self.config.drift_band {
+                continue;
+            }
+            let notional = slot.target * nav - values[i];
+            if notional.abs() < self.min_notional(slot) {
+                continue;
+            }
+            drifts.push(Drift {
+                slot: i,
+                notional,
+                mid: mids[i],
+            });
+        }
+        Ok(drifts)
+    }
+
+    fn start_rebalance(&mut self, ctx: &mut dyn StrategyContext) {
+        let drifts = match self.drifts(ctx) {
+            Ok(drifts) => drifts,
+            Err(instrument) => {
+                ctx.log_warn(&format!(
+                    "No mid price for {} — skipping drift check (is it in the top-level markets?)",
+                    instrument
+                ));
+                return;
+            }
+        };
+        if drifts.is_empty() {
+            return;
+        }
+        for d in &drifts {
+            ctx.log_info(&format!(
+                "{} drifted: trade {} {} to reach {}",
+                self.slots[d.slot].instrument,
+                if d.notional > Decimal::ZERO {
+                    "buy"
+                } else {
+                    "sell"
+                },
+                d.notional.abs().round_dp(2),
+                self.slots[d.slot].target
+            ));
+        }
+        self.place_sells(ctx, &drifts);
+        if self.state.pending.is_empty() {
+            self.place_buys(ctx);
+        } else {
+            self.state.phase = Phase::Selling;
+        }
+    }
+
+    fn place_sells(&mut self, ctx: &mut dyn StrategyContext, drifts: &[Drift]) {
+        for d in drifts.iter().filter(|d| d.notional < Decimal::ZERO) {
+            let slot = &self.slots[d.slot];
+            let available = ctx.balance(&slot.meta.base_asset).available;
+            let qty = slot
+                .meta
+                .trunc_qty(Qty::new((-d.notional / d.mid).min(available)));
+            if qty.is_zero() {
+                continue;
+            }
+            let touch = ctx
+                .quote(&slot.instrument)
+                .map(|q| q.bid.0)
+                .unwrap_or(d.mid);
+            let price = touch * (Decimal::ONE - self.config.max_slippage_pct);
+            self.place_taker(ctx, d.slot, OrderSide::Sell, price, qty);
+        }
+    }
+
+    /// Buy underweight slots from fresh balances, after any sells have
+    /// settled. Buys are scaled down together if quote cash runs short.
+    fn place_buys(&mut self, ctx: &mut dyn StrategyContext) {
+        let drifts = match self.drifts(ctx) {
+            Ok(drifts) => drifts,
+            Err(instrument) => {
+                ctx.log_warn(&format!("No mid price for {} — skipping buys", instrument));
+                Vec::new()
+            }
+        };
+        let buys: Vec<&Drift> = drifts
+            .iter()
+            .filter(|d| d.notional > Decimal::ZERO)
+            .collect();
+        let wanted: Decimal = buys.iter().map(|d| d.notional).sum();
+        if wanted.is_zero() {
+            self.finish_rebalance(ctx);
+            return;
+        }
+
+        let cash = match self.quote_asset {
+            Some(ref asset) => ctx.balance(asset).available,
+            None => Decimal::ZERO,
+        };
+        let budget = cash / (Decimal::ONE + self.config.max_slippage_pct);
+        let scale = (budget / wanted).min(Decimal::ONE);
+        if scale < Decimal::ONE {
+            ctx.log_warn(&format!(
+                "Quote balance {} short of {} — scaling buys to {}",
+                cash,
+                wanted.round_dp(2),
+                scale.round_dp(4)
+            ));
+        }
+
+        for d in buys {
+            let slot = &self.slots[d.slot];
+            let touch = ctx
+                .quote(&slot.instrument)
+                .map(|q| q.ask.0)
+                .unwrap_or(d.mid);
+            let price = touch * (Decimal::ONE + self.config.max_slippage_pct);
+            let qty = slot.meta.trunc_qty(Qty::new(d.notional * scale / price));
+            // Scaling can push a buy under the market minimum — skip it
+            // rather than have it rejected.
+            if qty.is_zero() || qty.0 * price < self.min_notional(slot) {
+                continue;
+            }
+            self.place_taker(ctx, d.slot, OrderSide::Buy, price, qty);
+        }
+        if self.state.pending.is_empty() {
+            self.finish_rebalance(ctx);
+        } else {
+            self.state.phase = Phase::Buying;
+        }
+    }
+
+    /// Place a marketable IOC limit; nothing is left resting on the book.
+    fn place_taker(
+        &mut self,
+        ctx: &mut dyn StrategyContext,
+        slot: usize,
+        side: OrderSide,
+        raw_price: Decimal,
+        qty: Qty,
+    ) {
+        let slot = &self.slots[slot];
+        let price = slot
+            .meta
+            .round_price(Price::new(raw_price).trim_to_sig_figs(5));
+        let order = PlaceOrder::limit(
+            slot.exchange.clone(),
+            slot.instrument.clone(),
+            side,
+            price,
+            qty,
+        )
+        .with_tif(TimeInForce::Ioc);
+        self.state.pending.push(order.client_id.clone());
+        ctx.place_order(order);
+        ctx.log_info(&format!(
+            "{} {} IOC placed @ {} qty={}",
+            slot.instrument, side, price, qty
+        ));
+    }
+
+    fn finish_rebalance(&mut self, ctx: &mut dyn StrategyContext) {
+        if self.state.phase != Phase::Idle {
+            self.state.rebalances += 1;
+            ctx.log_info(&format!("Rebalance {} complete", self.state.rebalances));
+        }
+        self.state.phase = Phase::Idle;
+    }
+
+    /// An order of the current leg reached a terminal state. Once the leg
+    /// is done, sells hand over to buys and buys end the rebalance.
+    fn on_order_done(&mut self, ctx: &mut dyn StrategyContext, client_id: &ClientOrderId) {
+        let before = self.state.pending.len();
+        self.state.pending.retain(|id| id != client_id);
+        if self.state.pending.len() == before || !self.state.pending.is_empty() {
+            return;
+        }
+        match self.state.phase {
+            Phase::Selling => self.place_buys(ctx),
+            Phase::Buying => self.finish_rebalance(ctx),
+            Phase::Idle => {}
+        }
+    }
+}
+
+impl Strategy for RebalanceStrategy {
+    fn id(&self) -> &StrategyId {
+        &self.config.strategy_id
+    }
+
+    fn on_start(&mut self, ctx: &mut dyn StrategyContext) {
+        let errors = self.config.validate();
+        if !errors.is_empty() {
+            ctx.stop_strategy(self.config.strategy_id.clone(), &errors.join("; "));
+            return;
+        }
+
+        for (base, weight) in &self.config.assets {
+            let asset = AssetId::new(base.as_str());
+            let found = self
+                .markets
+                .iter()
+                .filter(|m| m.is_spot())
+                .filter_map(|m| {
+                    let instrument = m.instrument_id();
+                    let meta = ctx.instrument_meta(&instrument)?;
+                    (meta.base_asset == asset).then(|| (m, instrument, meta.clone()))
+                })
+                .next();
+            let (market, instrument, meta) = match found {
+                Some(found) => found,
+                None => {
+                    ctx.stop_strategy(
+                        self.config.strategy_id.clone(),
+                        &format!("No spot market for {} in the top-level markets", base),
+                    );
+                    return;
+                }
+            };
+            self.slots.push(Slot {
+                exchange: market.exchange_instance(self.environment),
+                instrument,
+                meta,
+                target: *weight,
+            });
+        }
+
+        let quote_asset = self.slots[0].meta.quote_asset.clone();
+        if self.slots.iter().any(|s| s.meta.quote_asset != quote_asset) {
+            ctx.stop_strategy(
+                self.config.strategy_id.clone(),
+                "All assets must share one quote asset",
+            );
+            return;
+        }
+        let cash_weight = Decimal::ONE - self.slots.iter().map(|s| s.target).sum::<Decimal>();
+        ctx.log_info(&format!(
+            "RebalanceStrategy started: {} assets, {} weight {}, band={}",
+            self.slots.len(),
+            quote_asset,
+            cash_weight,
+            self.config.drift_band
+        ));
+        self.quote_asset = Some(quote_asset);
+
+        ctx.set_interval(Duration::from_secs(self.config.check_interval_secs));
+    }
+
+    fn on_event(&mut self, ctx: &mut dyn StrategyContext, event: &Event) {
+        match event {
+            Event::OrderFilled(f) => {
+                ctx.log_info(&format!(
+                    "Filled: {} {} @ {} qty={}",
+                    f.side, f.client_id, f.price, f.qty
+                ));
+            }
+            Event::OrderCompleted(c) => self.on_order_done(ctx, &c.client_id),
+            Event::OrderCanceled(c) => self.on_order_done(ctx, &c.client_id),
+            Event::OrderRejected(r) => {
+                ctx.log_warn(&format!("Rejected: {} reason={}", r.client_id, r.reason));
+                self.on_order_done(ctx, &r.client_id);
+            }
+            Event::ExchangeStateChanged(e) => {
+                self.state.halted = matches!(e.new_state, ExchangeHealth::Halted);
+                if self.state.halted {
+                    ctx.log_warn(&format!(
+                        "Exchange halted ({}) — pausing rebalances",
+                        e.reason
+                    ));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn on_timer(&mut self, ctx: &mut dyn StrategyContext, _timer_id: TimerId) {
+        // A rebalance still in flight is finished before the next check
+        if self.state.halted || self.state.phase != Phase::Idle {
+            return;
+        }
+        self.start_rebalance(ctx);
+    }
+
+    fn on_stop(&mut self, ctx: &mut dyn StrategyContext) {
+        if let Some(slot) = self.slots.first() {
+            ctx.cancel_all(CancelAll::new(slot.exchange.clone()));
+        }
+        ctx.log_info(&format!(
+            "RebalanceStrategy stopped after {} rebalances",
+            self.state.rebalances
+        ));
+    }
+}
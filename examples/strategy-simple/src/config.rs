@@ -2,21 +2,43 @@
 
 use bot_core::{Environment, Market, StrategyId};
 use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+/// Which side of the market the strategy opens positions on.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum Direction {
+    /// Buy at `buy_price`, take profit by selling at `sell_price`
+    #[default]
+    Long,
+    /// Sell at `sell_price`, take profit by buying back at `buy_price` (perps only)
+    Short,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct SimpleConfig {
     pub strategy_id: StrategyId,
     pub environment: Environment,
     pub market: Market,
 
-    /// Price at which to place a BUY order
+    /// Side to open positions on (default: long)
+    #[serde(default)]
+    pub direction: Direction,
+    /// Price at which to BUY (long entry / short take profit)
     pub buy_price: Decimal,
-    /// Price at which to place a SELL order (take profit)
+    /// Price at which to SELL (long take profit / short entry)
     pub sell_price: Decimal,
     /// Order quantity in base asset
     pub order_size: Decimal,
+    /// Fee rate per fill, used to warn when the band can't cover a round trip
+    #[serde(default = "default_fee_rate")]
+    pub fee_rate: Decimal,
+}
+
+fn default_fee_rate() -> Decimal {
+    dec!(0.00025)
 }
 
 impl SimpleConfig {
@@ -28,6 +50,16 @@ impl SimpleConfig {
         if self.order_size <= Decimal::ZERO {
             errors.push("order_size must be > 0".into());
         }
+        if self.direction == Direction::Short && self.market.is_spot() {
+            errors.push("direction \"short\" requires a perp market".into());
+        }
         errors
     }
+
+    /// Profit per unit of base for one full cycle after entry and exit fees.
+    /// Zero or negative means every completed cycle loses money.
+    pub fn round_trip_edge(&self) -> Decimal {
+        let fees = self.fee_rate * (self.buy_price + self.sell_price);
+        self.sell_price - self.buy_price - fees
+    }
 }
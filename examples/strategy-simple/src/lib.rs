@@ -1,9 +1,12 @@
 //! Simple Buy-Low-Sell-High Strategy
 //!
-//! A minimal working strategy (~100 lines) that demonstrates the full pattern:
+//! A minimal working strategy (~240 lines) that demonstrates the full pattern:
 //! - Buy when price drops below `buy_price`
 //! - Sell when price rises above `sell_price`
 //! - Tracks one position at a time (no grid, no scaling)
+//! - `direction: "short"` runs the same cycle the other way round on perps:
+//!   sell high first, then buy back low with a reduce-only order
+//! - Logs each cycle's PnL net of fees, computed from its own fills
 
 mod config;
 mod state;
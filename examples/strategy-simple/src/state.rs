@@ -1,30 +1,52 @@
 //! Runtime state for the Simple strategy.
 
 use bot_core::ClientOrderId;
+use rust_decimal::Decimal;
 
 /// Tracks which phase the strategy is in.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Phase {
-    /// No position — waiting to buy
-    WaitingToBuy,
-    /// Buy order placed, waiting for fill
-    BuyPlaced,
-    /// Holding position — waiting to sell
-    WaitingToSell,
-    /// Sell order placed, waiting for fill
-    SellPlaced,
+    /// No position — waiting to open one
+    WaitingToEnter,
+    /// Entry order placed, waiting for fill
+    EntryPlaced,
+    /// Holding position — waiting to close it
+    WaitingToExit,
+    /// Exit order placed, waiting for fill
+    ExitPlaced,
 }
 
 pub struct SimpleState {
     pub phase: Phase,
     pub active_order: Option<ClientOrderId>,
+    /// Signed position from our own fills (positive = long, negative = short),
+    /// net of fees taken in kind
+    pub position_qty: Decimal,
+    /// Quote-asset cash flow of the current cycle (sells +, buys −, fees −)
+    pub cycle_cash: Decimal,
+    /// Fees paid in the current cycle, valued in the quote asset
+    pub cycle_fees: Decimal,
+    /// `position_qty` when the current cycle started — sub-lot dust carried
+    /// over from earlier exits
+    pub cycle_start_qty: Decimal,
+    /// Running totals over completed cycles
+    pub realized_pnl: Decimal,
+    pub total_fees: Decimal,
+    pub cycles: u64,
 }
 
 impl SimpleState {
     pub fn new() -> Self {
         Self {
-            phase: Phase::WaitingToBuy,
+            phase: Phase::WaitingToEnter,
             active_order: None,
+            position_qty: Decimal::ZERO,
+            cycle_cash: Decimal::ZERO,
+            cycle_fees: Decimal::ZERO,
+            cycle_start_qty: Decimal::ZERO,
+            realized_pnl: Decimal::ZERO,
+            total_fees: Decimal::ZERO,
+            cycles: 0,
         }
     }
 }
@@ -1,11 +1,15 @@
 //! Simple Buy-Low-Sell-High strategy implementation.
 //!
 //! Lifecycle:
-//!   WaitingToBuy → BuyPlaced → WaitingToSell → SellPlaced → WaitingToBuy (repeat)
+//!   WaitingToEnter → EntryPlaced → WaitingToExit → ExitPlaced → WaitingToEnter (repeat)
+//!
+//! Long entries buy at `buy_price` and exit at `sell_price`; short entries
+//! sell at `sell_price` and buy back at `buy_price`.
 
-use crate::config::SimpleConfig;
+use crate::config::{Direction, SimpleConfig};
 use crate::state::{Phase, SimpleState};
 use bot_core::*;
+use rust_decimal::Decimal;
 
 pub struct SimpleStrategy {
     config: SimpleConfig,
@@ -32,52 +36,124 @@ impl SimpleStrategy {
         self.config.market.instrument_id()
     }
 
-    fn place_buy(&mut self, ctx: &mut dyn StrategyContext) {
-        let price = self
-            .meta
-            .as_ref()
-            .unwrap()
-            .round_price(Price::new(self.config.buy_price));
-        let qty = self
-            .meta
-            .as_ref()
-            .unwrap()
-            .round_qty(Qty::new(self.config.order_size));
-        let order = PlaceOrder::limit(
-            self.exchange(),
-            self.instrument(),
-            OrderSide::Buy,
-            price,
-            qty,
-        );
+    /// Warn about config values finer than the instrument's tick/lot size —
+    /// they would otherwise be rounded silently at order time.
+    fn check_precision(&self, ctx: &mut dyn StrategyContext) {
+        let meta = self.meta.as_ref().unwrap();
+        for (name, value) in [
+            ("buy_price", self.config.buy_price),
+            ("sell_price", self.config.sell_price),
+        ] {
+            let rounded = meta.round_price(Price::new(value));
+            if rounded.0 != value {
+                ctx.log_warn(&format!(
+                    "{} {} is off-tick (tick={}), orders will use {}",
+                    name, value, meta.tick_size, rounded
+                ));
+            }
+        }
+        let qty = meta.round_qty(Qty::new(self.config.order_size));
+        if qty.0 != self.config.order_size {
+            ctx.log_warn(&format!(
+                "order_size {} is off-lot (lot={}), orders will use {}",
+                self.config.order_size, meta.lot_size, qty
+            ));
+        }
+    }
+
+    fn place_entry(&mut self, ctx: &mut dyn StrategyContext) {
+        let (side, raw_price) = match self.config.direction {
+            Direction::Long => (OrderSide::Buy, self.config.buy_price),
+            Direction::Short => (OrderSide::Sell, self.config.sell_price),
+        };
+        let meta = self.meta.as_ref().unwrap();
+        let price = meta.round_price(Price::new(raw_price));
+        // Entries open a position, so there is nothing held to oversell —
+        // standard rounding on both sides.
+        let qty = meta.round_qty(Qty::new(self.config.order_size));
+        let order = PlaceOrder::limit(self.exchange(), self.instrument(), side, price, qty);
         self.state.active_order = Some(order.client_id.clone());
-        self.state.phase = Phase::BuyPlaced;
+        self.state.phase = Phase::EntryPlaced;
         ctx.place_order(order);
-        ctx.log_info(&format!("BUY order placed @ {}", price));
+        ctx.log_info(&format!("{} entry placed @ {}", side, price));
     }
 
-    fn place_sell(&mut self, ctx: &mut dyn StrategyContext) {
-        let price = self
-            .meta
-            .as_ref()
-            .unwrap()
-            .round_price(Price::new(self.config.sell_price));
-        let qty = self
-            .meta
-            .as_ref()
-            .unwrap()
-            .trunc_qty(Qty::new(self.config.order_size));
-        let order = PlaceOrder::limit(
-            self.exchange(),
-            self.instrument(),
-            OrderSide::Sell,
-            price,
-            qty,
-        );
+    fn place_exit(&mut self, ctx: &mut dyn StrategyContext) {
+        let (side, raw_price) = match self.config.direction {
+            Direction::Long => (OrderSide::Sell, self.config.sell_price),
+            Direction::Short => (OrderSide::Buy, self.config.buy_price),
+        };
+        let meta = self.meta.as_ref().unwrap();
+        let price = meta.round_price(Price::new(raw_price));
+        // Close what we actually hold, not `order_size`: spot buys pay the fee
+        // in the base asset, so the full order size is no longer available.
+        // Truncate so the exit never exceeds the position.
+        let held = self.state.position_qty.abs();
+        let qty = meta.trunc_qty(Qty::new(held));
+        if qty.is_zero() {
+            ctx.log_warn(&format!(
+                "Position {} — below lot size, nothing to close",
+                self.state.position_qty
+            ));
+            self.place_entry(ctx);
+            return;
+        }
+        let dust = held - qty.0;
+        if !dust.is_zero() {
+            ctx.log_info(&format!("{} dust left below lot size", dust));
+        }
+        let mut order = PlaceOrder::limit(self.exchange(), self.instrument(), side, price, qty);
+        if !self.config.market.is_spot() {
+            // Never flip the position if fills race the exit
+            order = order.reduce_only();
+        }
         self.state.active_order = Some(order.client_id.clone());
-        self.state.phase = Phase::SellPlaced;
+        self.state.phase = Phase::ExitPlaced;
         ctx.place_order(order);
-        ctx.log_info(&format!("SELL order placed @ {}", price));
+        ctx.log_info(&format!("{} exit placed @ {}", side, price));
+    }
+
+    /// Book a fill into the current cycle's cash flow. Fees charged in the
+    /// quote asset are deducted here; spot BUY fees taken in the base asset
+    /// already show up as a smaller `net_qty` to sell back.
+    fn record_fill(&mut self, f: &OrderFilledEvent) {
+        let notional = f.price.0 * f.qty.0;
+        match f.side {
+            OrderSide::Buy => self.state.cycle_cash -= notional,
+            OrderSide::Sell => self.state.cycle_cash += notional,
+        }
+        let quote_asset = &self.meta.as_ref().unwrap().quote_asset;
+        if &f.fee.asset == quote_asset {
+            self.state.cycle_cash -= f.fee.amount;
+            self.state.cycle_fees += f.fee.amount;
+        } else {
+            self.state.cycle_fees += f.fee.amount * f.price.0;
+        }
+    }
+
+    /// Close the books on a completed round trip and log its result.
+    ///
+    /// The exit only closes whole lots, so sub-lot dust can stay behind (or
+    /// dust from earlier cycles can be sold off). Valuing the change in
+    /// carried position at `exit_px` keeps that dust from showing up as a
+    /// loss in one cycle and a gain in a later one.
+    fn finish_cycle(&mut self, ctx: &mut dyn StrategyContext, exit_px: Decimal) {
+        let carried = self.state.position_qty - self.state.cycle_start_qty;
+        let pnl = self.state.cycle_cash + carried * exit_px;
+        self.state.realized_pnl += pnl;
+        self.state.total_fees += self.state.cycle_fees;
+        self.state.cycles += 1;
+        ctx.log_info(&format!(
+            "Cycle {} PnL={} (fees {}) — total PnL={} fees={}",
+            self.state.cycles,
+            pnl,
+            self.state.cycle_fees,
+            self.state.realized_pnl,
+            self.state.total_fees
+        ));
+        self.state.cycle_cash = Decimal::ZERO;
+        self.state.cycle_fees = Decimal::ZERO;
+        self.state.cycle_start_qty = self.state.position_qty;
     }
 }
 
@@ -97,35 +173,74 @@ impl Strategy for SimpleStrategy {
             ctx.stop_strategy(self.config.strategy_id.clone(), &errors.join("; "));
             return;
         }
+        self.check_precision(ctx);
+        let edge = self.config.round_trip_edge();
+        if edge <= Decimal::ZERO {
+            ctx.log_warn(&format!(
+                "sell_price - buy_price does not cover fees at fee_rate={} (edge {} per unit)",
+                self.config.fee_rate, edge
+            ));
+        }
         ctx.log_info(&format!(
-            "SimpleStrategy started: buy@{} sell@{} qty={}",
-            self.config.buy_price, self.config.sell_price, self.config.order_size
+            "SimpleStrategy started: {:?} buy@{} sell@{} qty={}",
+            self.config.direction,
+            self.config.buy_price,
+            self.config.sell_price,
+            self.config.order_size
         ));
-        // Place initial buy order
-        self.place_buy(ctx);
+        // Place initial entry order
+        self.place_entry(ctx);
     }
 
     fn on_event(&mut self, ctx: &mut dyn StrategyContext, event: &Event) {
         match event {
+            Event::OrderFilled(f) if self.state.active_order.as_ref() == Some(&f.client_id) => {
+                match f.side {
+                    OrderSide::Buy => self.state.position_qty += f.net_qty.0,
+                    OrderSide::Sell => self.state.position_qty -= f.qty.0,
+                }
+                self.record_fill(f);
+            }
             Event::OrderCompleted(c) => match self.state.phase {
-                Phase::BuyPlaced => {
-                    ctx.log_info(&format!("Buy filled @ avg={:?}", c.avg_fill_px));
-                    self.place_sell(ctx);
+                Phase::EntryPlaced => {
+                    ctx.log_info(&format!("Entry filled @ avg={:?}", c.avg_fill_px));
+                    self.place_exit(ctx);
                 }
-                Phase::SellPlaced => {
+                Phase::ExitPlaced => {
                     ctx.log_info(&format!(
-                        "Sell filled @ avg={:?} — cycle complete!",
+                        "Exit filled @ avg={:?} — cycle complete!",
                         c.avg_fill_px
                     ));
-                    self.place_buy(ctx);
+                    let exit_px = match c.avg_fill_px {
+                        Some(px) => px.0,
+                        None => match self.config.direction {
+                            Direction::Long => self.config.sell_price,
+                            Direction::Short => self.config.buy_price,
+                        },
+                    };
+                    self.finish_cycle(ctx, exit_px);
+                    self.place_entry(ctx);
                 }
                 _ => {}
             },
             Event::OrderCanceled(_) | Event::OrderRejected(_) => {
-                ctx.log_warn("Order canceled/rejected — resetting to buy phase");
                 self.state.active_order = None;
-                self.state.phase = Phase::WaitingToBuy;
-                self.place_buy(ctx);
+                // Go by the tracked position, not the phase: a partly filled
+                // entry leaves a position too, and re-entering on top of it
+                // would double it instead of closing it.
+                let held = Qty::new(self.state.position_qty.abs());
+                if !self.meta.as_ref().unwrap().trunc_qty(held).is_zero() {
+                    ctx.log_warn(&format!(
+                        "Order canceled/rejected with position {} open — placing exit",
+                        self.state.position_qty
+                    ));
+                    self.state.phase = Phase::WaitingToExit;
+                    self.place_exit(ctx);
+                } else {
+                    ctx.log_warn("Order canceled/rejected — resetting to entry phase");
+                    self.state.phase = Phase::WaitingToEnter;
+                    self.place_entry(ctx);
+                }
             }
             _ => {}
         }
@@ -135,6 +250,9 @@ impl Strategy for SimpleStrategy {
 
     fn on_stop(&mut self, ctx: &mut dyn StrategyContext) {
         ctx.cancel_all(CancelAll::new(self.exchange()));
-        ctx.log_info("SimpleStrategy stopped");
+        ctx.log_info(&format!(
+            "SimpleStrategy stopped after {} cycles: PnL={} fees={}",
+            self.state.cycles, self.state.realized_pnl, self.state.total_fees
+        ));
     }
 }
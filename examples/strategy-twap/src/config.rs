@@ -0,0 +1,80 @@
+//! Configuration for the TWAP strategy.
+
+use bot_core::{Environment, Market, OrderSide, StrategyId};
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum TwapSide {
+    Buy,
+    Sell,
+}
+
+impl From<TwapSide> for OrderSide {
+    fn from(side: TwapSide) -> Self {
+        match side {
+            TwapSide::Buy => OrderSide::Buy,
+            TwapSide::Sell => OrderSide::Sell,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct TwapConfig {
+    pub strategy_id: StrategyId,
+    pub environment: Environment,
+    pub market: Market,
+
+    pub side: TwapSide,
+    /// Total quantity to execute in base asset
+    pub total_qty: Decimal,
+    /// Time to spread execution over
+    pub duration_secs: u64,
+    /// Number of child orders
+    pub slices: u32,
+    /// Random +/- fraction applied to each slice's size and interval (0.2 = ±20%)
+    #[serde(default = "default_jitter_pct")]
+    pub jitter_pct: Decimal,
+    /// How far past mid a child order may cross (0.005 = 0.5%)
+    #[serde(default = "default_max_slippage_pct")]
+    pub max_slippage_pct: Decimal,
+    /// Seed for the jitter sequence — same seed, same schedule
+    #[serde(default)]
+    pub seed: u64,
+    /// Only reduce an existing position (perps)
+    #[serde(default)]
+    pub reduce_only: bool,
+}
+
+fn default_jitter_pct() -> Decimal {
+    dec!(0.2)
+}
+
+fn default_max_slippage_pct() -> Decimal {
+    dec!(0.005)
+}
+
+impl TwapConfig {
+    pub fn validate(&self) -> Vec<String> {
+        let mut errors = Vec::new();
+        if self.total_qty <= Decimal::ZERO {
+            errors.push("total_qty must be > 0".into());
+        }
+        if self.duration_secs == 0 {
+            errors.push("duration_secs must be > 0".into());
+        }
+        if self.slices == 0 {
+            errors.push("slices must be > 0".into());
+        }
+        if self.jitter_pct < Decimal::ZERO || self.jitter_pct >= Decimal::ONE {
+            errors.push("jitter_pct must be in [0, 1)".into());
+        }
+        if self.max_slippage_pct < Decimal::ZERO {
+            errors.push("max_slippage_pct must be >= 0".into());
+        }
+        errors
+    }
+}
@@ -0,0 +1,180 @@
+//! Reusable TWAP executor.
+//!
+//! Self-contained: copy this file into any strategy, call `poll` from
+//! `on_timer` and `on_event` from `on_event`. Children are IOC orders, so
+//! nothing rests on the book between slices.
+
+use bot_core::*;
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+
+const GOLDEN: u64 = 0x9E37_79B9_7F4A_7C15;
+
+/// What to execute and over how long.
+#[derive(Debug, Clone)]
+pub struct TwapParams {
+    pub side: OrderSide,
+    /// Gross quantity to fill in base asset
+    pub total_qty: Decimal,
+    pub duration_ms: i64,
+    pub slices: u32,
+    /// Random +/- fraction applied to each slice's size and interval
+    pub jitter_pct: Decimal,
+    /// How far past mid a child order may cross
+    pub max_slippage_pct: Decimal,
+    pub seed: u64,
+    pub reduce_only: bool,
+}
+
+pub struct TwapExecutor {
+    params: TwapParams,
+    exchange: ExchangeInstance,
+    meta: InstrumentMeta,
+    slice_qty: Decimal,
+    slice_interval_ms: i64,
+    end_ms: i64,
+    rng: u64,
+    filled_qty: Decimal,
+    next_slice_ms: i64,
+    active_child: Option<ClientOrderId>,
+}
+
+impl TwapExecutor {
+    /// The first slice goes out on the first `poll` at or after `start_ms`.
+    pub fn new(
+        params: TwapParams,
+        exchange: ExchangeInstance,
+        meta: InstrumentMeta,
+        start_ms: i64,
+    ) -> Self {
+        let slice_qty = params.total_qty / Decimal::from(params.slices);
+        let slice_interval_ms = params.duration_ms / params.slices as i64;
+        let end_ms = start_ms + params.duration_ms;
+        let rng = params.seed.wrapping_mul(GOLDEN) | 1;
+        Self {
+            params,
+            exchange,
+            meta,
+            slice_qty,
+            slice_interval_ms,
+            end_ms,
+            rng,
+            filled_qty: Decimal::ZERO,
+            next_slice_ms: start_ms,
+            active_child: None,
+        }
+    }
+
+    pub fn filled_qty(&self) -> Decimal {
+        self.filled_qty
+    }
+
+    pub fn remaining_qty(&self) -> Decimal {
+        (self.params.total_qty - self.filled_qty).max(Decimal::ZERO)
+    }
+
+    /// Done once no child is in flight and less than one lot remains.
+    pub fn is_done(&self) -> bool {
+        self.active_child.is_none()
+            && self
+                .meta
+                .trunc_qty(Qty::new(self.remaining_qty()))
+                .is_zero()
+    }
+
+    /// Send the next child order if one is due.
+    ///
+    /// Past `duration_ms`, each slice sweeps the whole remainder, so slices
+    /// that went unfilled are caught up rather than dropped.
+    pub fn poll(&mut self, ctx: &mut dyn StrategyContext) {
+        let now = ctx.now_ms();
+        if self.active_child.is_some() || now < self.next_slice_ms || self.is_done() {
+            return;
+        }
+        let mid = match ctx.mid_price(&self.meta.instrument_id) {
+            Some(mid) => mid,
+            None => return,
+        };
+
+        let remaining = self.remaining_qty();
+        let raw_qty = if now >= self.end_ms {
+            remaining
+        } else {
+            let size_jitter = Decimal::ONE + self.params.jitter_pct * self.next_jitter();
+            (self.slice_qty * size_jitter).min(remaining)
+        };
+        // Slices below one lot wait for the end-of-schedule sweep
+        let qty = self.meta.trunc_qty(Qty::new(raw_qty));
+        if qty.is_zero() {
+            return;
+        }
+
+        let cross = match self.params.side {
+            OrderSide::Buy => Decimal::ONE + self.params.max_slippage_pct,
+            OrderSide::Sell => Decimal::ONE - self.params.max_slippage_pct,
+        };
+        let price = self
+            .meta
+            .round_price(Price::new(mid.0 * cross).trim_to_sig_figs(5));
+        let mut order = PlaceOrder::limit(
+            self.exchange.clone(),
+            self.meta.instrument_id.clone(),
+            self.params.side,
+            price,
+            qty,
+        )
+        .with_tif(TimeInForce::Ioc);
+        if self.params.reduce_only {
+            order = order.reduce_only();
+        }
+        self.active_child = Some(order.client_id.clone());
+
+        let interval_jitter = Decimal::ONE + self.params.jitter_pct * self.next_jitter();
+        let interval = (Decimal::from(self.slice_interval_ms) * interval_jitter)
+            .to_i64()
+            .unwrap_or(self.slice_interval_ms);
+        self.next_slice_ms = now + interval;
+
+        ctx.place_order(order);
+        ctx.log_debug(&format!(
+            "TWAP child {} {} @ {} ({} of {} filled)",
+            self.params.side, qty, price, self.filled_qty, self.params.total_qty
+        ));
+    }
+
+    /// Feed every event. Returns true if it concerned the in-flight child.
+    pub fn on_event(&mut self, event: &Event) -> bool {
+        let child = match &self.active_child {
+            Some(child) => child,
+            None => return false,
+        };
+        match event {
+            Event::OrderFilled(f) if &f.client_id == child => {
+                self.filled_qty += f.qty.0;
+                true
+            }
+            Event::OrderCompleted(c) if &c.client_id == child => {
+                self.active_child = None;
+                true
+            }
+            Event::OrderCanceled(c) if &c.client_id == child => {
+                self.active_child = None;
+                true
+            }
+            Event::OrderRejected(r) if &r.client_id == child => {
+                self.active_child = None;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Uniform in [-1, 1] from a xorshift sequence seeded by `params.seed`,
+    /// so backtests replay the same schedule.
+    fn next_jitter(&mut self) -> Decimal {
+        self.rng ^= self.rng << 13;
+        self.rng ^= self.rng >> 7;
+        self.rng ^= self.rng << 17;
+        Decimal::from(self.rng % 2001) / Decimal::from(1000) - Decimal::ONE
+    }
+}
@@ -0,0 +1,18 @@
+//! TWAP Execution Strategy
+//!
+//! Works a target quantity into the market over a fixed duration:
+//! - `TwapExecutor` (executor.rs) slices the target into IOC child orders
+//!   with seeded size/timing jitter — copy it into any strategy that needs
+//!   to scale in or out without a resting footprint
+//! - `TwapStrategy` drives one executor from an interval timer and stops
+//!   when the target is filled
+
+mod config;
+mod executor;
+mod state;
+mod strategy;
+
+pub use config::*;
+pub use executor::*;
+pub use state::*;
+pub use strategy::*;
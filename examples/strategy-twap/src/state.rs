@@ -0,0 +1,25 @@
+//! Runtime state for the TWAP strategy.
+
+use crate::executor::TwapExecutor;
+
+pub struct TwapState {
+    /// Built in `on_start` once instrument meta is known
+    pub executor: Option<TwapExecutor>,
+    /// Exchange reported Halted — no new slices until Active again
+    pub halted: bool,
+}
+
+impl TwapState {
+    pub fn new() -> Self {
+        Self {
+            executor: None,
+            halted: false,
+        }
+    }
+}
+
+impl Default for TwapState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,134 @@
+//! TWAP strategy implementation.
+//!
+//! A thin driver around `TwapExecutor`: a 1s interval timer polls for the
+//! next slice, and the strategy stops itself once the target is filled.
+
+use crate::config::TwapConfig;
+use crate::executor::{TwapExecutor, TwapParams};
+use crate::state::TwapState;
+use bot_core::*;
+use rust_decimal::Decimal;
+use std::time::Duration;
+
+pub struct TwapStrategy {
+    config: TwapConfig,
+    state: TwapState,
+}
+
+impl TwapStrategy {
+    pub fn new(config: TwapConfig) -> Self {
+        Self {
+            config,
+            state: TwapState::new(),
+        }
+    }
+
+    fn exchange(&self) -> ExchangeInstance {
+        self.config
+            .market
+            .exchange_instance(self.config.environment)
+    }
+
+    fn params(&self) -> TwapParams {
+        TwapParams {
+            side: self.config.side.into(),
+            total_qty: self.config.total_qty,
+            duration_ms: self.config.duration_secs as i64 * 1000,
+            slices: self.config.slices,
+            jitter_pct: self.config.jitter_pct,
+            max_slippage_pct: self.config.max_slippage_pct,
+            seed: self.config.seed,
+            reduce_only: self.config.reduce_only,
+        }
+    }
+}
+
+impl Strategy for TwapStrategy {
+    fn id(&self) -> &StrategyId {
+        &self.config.strategy_id
+    }
+
+    fn on_start(&mut self, ctx: &mut dyn StrategyContext) {
+        let meta = match ctx
+            .instrument_meta(&self.config.market.instrument_id())
+            .cloned()
+        {
+            Some(meta) => meta,
+            None => {
+                ctx.stop_strategy(self.config.strategy_id.clone(), "Instrument not found");
+                return;
+            }
+        };
+        let errors = self.config.validate();
+        if !errors.is_empty() {
+            ctx.stop_strategy(self.config.strategy_id.clone(), &errors.join("; "));
+            return;
+        }
+        let slice_qty = self.config.total_qty / Decimal::from(self.config.slices);
+        if meta.trunc_qty(Qty::new(slice_qty)).is_zero() {
+            ctx.log_warn(&format!(
+                "Slice size {} is below lot size {} — execution will wait for the final sweep",
+                slice_qty, meta.lot_size
+            ));
+        }
+        self.state.executor = Some(TwapExecutor::new(
+            self.params(),
+            self.exchange(),
+            meta,
+            ctx.now_ms(),
+        ));
+        ctx.set_interval(Duration::from_secs(1));
+        ctx.log_info(&format!(
+            "TwapStrategy started: {:?} {} over {}s in {} slices",
+            self.config.side, self.config.total_qty, self.config.duration_secs, self.config.slices
+        ));
+    }
+
+    fn on_event(&mut self, ctx: &mut dyn StrategyContext, event: &Event) {
+        if let Event::ExchangeStateChanged(e) = event {
+            self.state.halted = matches!(e.new_state, ExchangeHealth::Halted);
+            ctx.log_info(&format!("Exchange state: {:?} ({})", e.new_state, e.reason));
+            return;
+        }
+        if let Some(executor) = self.state.executor.as_mut() {
+            if executor.on_event(event) {
+                if let Event::OrderFilled(f) = event {
+                    ctx.log_info(&format!(
+                        "Slice filled {} @ {} — {} remaining",
+                        f.qty,
+                        f.price,
+                        executor.remaining_qty()
+                    ));
+                }
+            }
+        }
+    }
+
+    fn on_timer(&mut self, ctx: &mut dyn StrategyContext, _timer_id: TimerId) {
+        if self.state.halted {
+            return;
+        }
+        let executor = match self.state.executor.as_mut() {
+            Some(executor) => executor,
+            None => return,
+        };
+        if executor.is_done() {
+            let filled = executor.filled_qty();
+            ctx.log_info(&format!("TWAP complete: {} filled", filled));
+            ctx.stop_strategy(self.config.strategy_id.clone(), "TWAP complete");
+            return;
+        }
+        executor.poll(ctx);
+    }
+
+    fn on_stop(&mut self, ctx: &mut dyn StrategyContext) {
+        ctx.cancel_all(CancelAll::new(self.exchange()));
+        if let Some(executor) = &self.state.executor {
+            ctx.log_info(&format!(
+                "TwapStrategy stopped: {} of {} filled",
+                executor.filled_qty(),
+                self.config.total_qty
+            ));
+        }
+    }
+}
@@ -0,0 +1,58 @@
+//! Tick-to-bar aggregation.
+//!
+//! Pure `f64` math with no `bot_core` dependency — reusable as-is.
+
+/// A completed OHLC bar.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Bar {
+    pub open: f64,
+    pub high: f64,
+    pub low: f64,
+    pub close: f64,
+}
+
+struct PartialBar {
+    start_ms: i64,
+    bar: Bar,
+}
+
+/// Aggregates streaming prices into fixed-interval OHLC bars.
+pub struct BarBuilder {
+    interval_ms: i64,
+    current_bar: Option<PartialBar>,
+}
+
+impl BarBuilder {
+    pub fn new(interval_secs: u64) -> Self {
+        Self {
+            interval_ms: interval_secs as i64 * 1000,
+            current_bar: None,
+        }
+    }
+
+    /// Feed a tick. Returns `Some(Bar)` when the interval elapses.
+    ///
+    /// The bar closes on the first tick *after* the interval expires, and
+    /// that tick opens the next bar.
+    pub fn update(&mut self, price: f64, timestamp_ms: i64) -> Option<Bar> {
+        if let Some(current) = self.current_bar.as_mut() {
+            if timestamp_ms - current.start_ms < self.interval_ms {
+                current.bar.high = current.bar.high.max(price);
+                current.bar.low = current.bar.low.min(price);
+                current.bar.close = price;
+                return None;
+            }
+        }
+        let completed = self.current_bar.take().map(|p| p.bar);
+        self.current_bar = Some(PartialBar {
+            start_ms: timestamp_ms,
+            bar: Bar {
+                open: price,
+                high: price,
+                low: price,
+                close: price,
+            },
+        });
+        completed
+    }
+}
@@ -0,0 +1,89 @@
+//! Strategy configuration.
+//!
+//! This struct is deserialized from the strategy's section of the JSON
+//! config file. Like other indicator strategies, it holds strategy-only
+//! params: `market` and `environment` come from the top-level `BotConfig`
+//! and are passed to `MyStrategy::new` (see references/indicator-strategies.md).
+//! Derive `JsonSchema` so the CLI can generate a JSON schema for validation.
+
+use bot_core::StrategyId;
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Configuration for MyStrategy.
+///
+/// TODO: Add your signal parameters below.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct MyConfig {
+    /// Unique strategy identifier (e.g., "btc-mytaker")
+    pub strategy_id: StrategyId,
+
+    /// Order size in base asset (e.g., 0.01 BTC)
+    pub order_size: Decimal,
+
+    /// Bar duration in seconds (5 for testing, 60+ for production)
+    #[serde(default = "default_bar_interval_secs")]
+    pub bar_interval_secs: u64,
+
+    /// EMA period in bars for the placeholder signal
+    #[serde(default = "default_ema_period")]
+    pub ema_period: usize,
+
+    /// Exit when price falls this fraction below entry (0.01 = 1%)
+    pub stop_loss_pct: Decimal,
+
+    /// Exit when price rises this fraction above entry (0.02 = 2%)
+    pub take_profit_pct: Decimal,
+
+    /// How far past the touch a taker order may cross (0.005 = 0.5%)
+    #[serde(default = "default_max_slippage_pct")]
+    pub max_slippage_pct: Decimal,
+    // TODO: Add your signal parameters here.
+    // Examples:
+    // pub rsi_period: usize,
+    // pub oversold: f64,
+}
+
+fn default_bar_interval_secs() -> u64 {
+    60
+}
+
+fn default_ema_period() -> usize {
+    20
+}
+
+fn default_max_slippage_pct() -> Decimal {
+    dec!(0.005)
+}
+
+impl MyConfig {
+    /// Validate configuration. Return a list of error messages (empty = valid).
+    pub fn validate(&self) -> Vec<String> {
+        let mut errors = Vec::new();
+
+        if self.order_size <= Decimal::ZERO {
+            errors.push("order_size must be > 0".into());
+        }
+        if self.bar_interval_secs == 0 {
+            errors.push("bar_interval_secs must be > 0".into());
+        }
+        if self.ema_period == 0 {
+            errors.push("ema_period must be > 0".into());
+        }
+        if self.stop_loss_pct <= Decimal::ZERO || self.stop_loss_pct >= Decimal::ONE {
+            errors.push("stop_loss_pct must be between 0 and 1".into());
+        }
+        if self.take_profit_pct <= Decimal::ZERO {
+            errors.push("take_profit_pct must be > 0".into());
+        }
+        if self.max_slippage_pct < Decimal::ZERO {
+            errors.push("max_slippage_pct must be >= 0".into());
+        }
+
+        // TODO: Add your validation rules here.
+
+        errors
+    }
+}
@@ -0,0 +1,59 @@
+//! Signal indicator.
+//!
+//! Pure `f64` math with no `bot_core` dependency. The EMA below is a
+//! placeholder — swap in RSI, MACD, Bollinger Bands, etc.
+
+/// Exponential moving average, seeded with the SMA of the first `period` closes.
+pub struct Ema {
+    period: usize,
+    alpha: f64,
+    seed_sum: f64,
+    seen: usize,
+    value: Option<f64>,
+}
+
+impl Ema {
+    pub fn new(period: usize) -> Self {
+        Self {
+            period,
+            alpha: 2.0 / (period as f64 + 1.0),
+            seed_sum: 0.0,
+            seen: 0,
+            value: None,
+        }
+    }
+
+    /// Feed one completed bar's close.
+    /// Returns `None` during warmup, `Some(ema)` once `period` closes are seen.
+    pub fn update(&mut self, close: f64) -> Option<f64> {
+        match self.value {
+            Some(prev) => {
+                self.value = Some(prev + self.alpha * (close - prev));
+            }
+            None => {
+                self.seed_sum += close;
+                self.seen += 1;
+                if self.seen == self.period {
+                    self.value = Some(self.seed_sum / self.period as f64);
+                }
+            }
+        }
+        self.value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // TODO: Replace with reference values for your indicator.
+    #[test]
+    fn ema_seeds_with_sma_then_smooths() {
+        let mut ema = Ema::new(3);
+        assert_eq!(ema.update(1.0), None);
+        assert_eq!(ema.update(2.0), None);
+        assert_eq!(ema.update(3.0), Some(2.0));
+        // alpha = 2 / (3 + 1) = 0.5
+        assert_eq!(ema.update(6.0), Some(4.0));
+    }
+}
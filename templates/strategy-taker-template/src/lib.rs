@@ -0,0 +1,20 @@
+//! Signal-Driven Taker Strategy
+//!
+//! Template for bots that enter on a bar/indicator signal with a marketable
+//! IOC order, then manage one position with a stop-loss and take-profit.
+//! Nothing rests on the book — use `strategy-template` for quoting bots.
+//!
+//! Replace "MyStrategy" with your strategy name throughout.
+//! See STRATEGY_API.md and references/indicator-strategies.md.
+
+mod bar;
+mod config;
+mod indicator;
+mod state;
+mod strategy;
+
+pub use bar::*;
+pub use config::*;
+pub use indicator::*;
+pub use state::*;
+pub use strategy::*;
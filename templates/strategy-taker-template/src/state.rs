@@ -0,0 +1,75 @@
+//! Strategy runtime state.
+//!
+//! This struct tracks everything the strategy needs at runtime.
+//! Unlike config, this is NOT serialized — it's rebuilt on startup.
+
+use bot_core::{ClientOrderId, Price};
+use rust_decimal::Decimal;
+
+/// Position lifecycle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Phase {
+    /// Collecting bars until the indicator converges
+    WarmingUp,
+    /// Indicator ready, waiting for an entry signal
+    Watching,
+    /// Entry order in flight
+    Opening,
+    /// Position held, watching stop and target
+    InPosition,
+    /// Exit order in flight
+    Closing,
+}
+
+/// Runtime state for MyStrategy.
+///
+/// TODO: Add your tracking fields here.
+pub struct MyState {
+    pub phase: Phase,
+
+    /// Latest (bid, ask) — taker orders price off the touch
+    pub last_bid_ask: Option<(Price, Price)>,
+
+    /// Indicator value and close of the previous bar (for crossover signals)
+    pub prev_signal: Option<(f64, f64)>,
+
+    /// Currently active order (if any)
+    pub active_order: Option<ClientOrderId>,
+
+    /// Signed position from our own fills (positive = long)
+    pub position_qty: Decimal,
+
+    /// Gross price * qty and qty over entry fills, for the average entry price
+    pub entry_notional: Decimal,
+    pub entry_qty: Decimal,
+
+    /// Protective stop and profit target, set once the entry completes
+    pub stop_px: Option<Price>,
+    pub target_px: Option<Price>,
+
+    /// When the last exit order was placed, for retry throttling
+    pub last_exit_ms: i64,
+}
+
+impl MyState {
+    pub fn new() -> Self {
+        Self {
+            phase: Phase::WarmingUp,
+            last_bid_ask: None,
+            prev_signal: None,
+            active_order: None,
+            position_qty: Decimal::ZERO,
+            entry_notional: Decimal::ZERO,
+            entry_qty: Decimal::ZERO,
+            stop_px: None,
+            target_px: None,
+            last_exit_ms: 0,
+        }
+    }
+}
+
+impl Default for MyState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
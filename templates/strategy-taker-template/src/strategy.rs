@@ -0,0 +1,360 @@
+//! Strategy implementation.
+//!
+//! Event flow:
+//!   Quote → [BarBuilder] → Bar → [Indicator] → signal → IOC entry
+//!   Quote → stop / target check → IOC reduce-only exit
+//!
+//! Phases:
+//!   WarmingUp → Watching → Opening → InPosition → Closing → Watching (repeat)
+
+use crate::bar::{Bar, BarBuilder};
+use crate::config::MyConfig;
+use crate::indicator::Ema;
+use crate::state::{MyState, Phase};
+use bot_core::*;
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+
+/// Minimum gap between exit attempts, so an exit that keeps failing
+/// cannot turn into one order per quote.
+const EXIT_RETRY_MS: i64 = 1_000;
+
+/// MyStrategy trading strategy.
+///
+/// TODO: Rename to match your strategy (e.g., EmaCrossStrategy, BreakoutStrategy).
+pub struct MyStrategy {
+    config: MyConfig,
+    market: Market,
+    environment: Environment,
+    state: MyState,
+    instrument_meta: Option<InstrumentMeta>,
+    bar_builder: BarBuilder,
+    indicator: Ema,
+}
+
+impl MyStrategy {
+    /// `market` and `environment` come from the top-level `BotConfig`
+    /// (`config.primary_market()` / `config.parse_environment()`).
+    pub fn new(config: MyConfig, market: Market, environment: Environment) -> Self {
+        let bar_builder = BarBuilder::new(config.bar_interval_secs);
+        let indicator = Ema::new(config.ema_period);
+        Self {
+            config,
+            market,
+            environment,
+            state: MyState::new(),
+            instrument_meta: None,
+            bar_builder,
+            indicator,
+        }
+    }
+
+    /// Round price to tick size and 5 significant figures.
+    fn round_price(&self, price: Price) -> Price {
+        let trimmed = price.trim_to_sig_figs(5);
+        if let Some(ref meta) = self.instrument_meta {
+            meta.round_price(trimmed)
+        } else {
+            trimmed
+        }
+    }
+
+    /// Round quantity to lot size.
+    fn round_qty(&self, qty: Qty) -> Qty {
+        if let Some(ref meta) = self.instrument_meta {
+            meta.round_qty(qty)
+        } else {
+            qty
+        }
+    }
+
+    /// Floor quantity to lot size, so exits never exceed the position.
+    fn trunc_qty(&self, qty: Qty) -> Qty {
+        if let Some(ref meta) = self.instrument_meta {
+            meta.trunc_qty(qty)
+        } else {
+            qty
+        }
+    }
+
+    /// Get the exchange instance for order commands.
+    fn exchange_instance(&self) -> ExchangeInstance {
+        self.market.exchange_instance(self.environment)
+    }
+
+    /// Get the instrument ID.
+    fn instrument_id(&self) -> InstrumentId {
+        self.market.instrument_id()
+    }
+
+    /// Entry signal, evaluated once per completed bar.
+    ///
+    /// TODO: Replace with your signal. Placeholder: close crosses above the EMA.
+    fn entry_signal(&self, close: f64, ema: f64) -> bool {
+        match self.state.prev_signal {
+            Some((prev_close, prev_ema)) => prev_close <= prev_ema && close > ema,
+            None => false,
+        }
+    }
+
+    fn on_bar(&mut self, ctx: &mut dyn StrategyContext, bar: Bar) {
+        if let Some(ema) = self.indicator.update(bar.close) {
+            if self.state.phase == Phase::WarmingUp {
+                ctx.log_info(&format!("Indicator ready: ema={:.4}", ema));
+                self.state.phase = Phase::Watching;
+            }
+            if self.state.phase == Phase::Watching && self.entry_signal(bar.close, ema) {
+                ctx.log_info(&format!(
+                    "Entry signal: close={:.4} ema={:.4}",
+                    bar.close, ema
+                ));
+                let qty = self.round_qty(Qty::new(self.config.order_size));
+                self.state.entry_notional = Decimal::ZERO;
+                self.state.entry_qty = Decimal::ZERO;
+                if self.place_taker(ctx, OrderSide::Buy, qty, false) {
+                    self.state.phase = Phase::Opening;
+                }
+            }
+            self.state.prev_signal = Some((bar.close, ema));
+        }
+    }
+
+    /// Exit when mid crosses the stop or the target.
+    fn check_exit(&mut self, ctx: &mut dyn StrategyContext, mid: Price) {
+        let reason = match (self.state.stop_px, self.state.target_px) {
+            (Some(stop), _) if mid.0 <= stop.0 => "Stop-loss",
+            (_, Some(target)) if mid.0 >= target.0 => "Take-profit",
+            _ => return,
+        };
+        let now = ctx.now_ms();
+        if now - self.state.last_exit_ms < EXIT_RETRY_MS {
+            return;
+        }
+        ctx.log_info(&format!("{} hit @ mid={}", reason, mid));
+        let qty = self.trunc_qty(Qty::new(self.state.position_qty));
+        if self.place_taker(ctx, OrderSide::Sell, qty, true) {
+            self.state.last_exit_ms = now;
+            self.state.phase = Phase::Closing;
+        }
+    }
+
+    /// Re-read the position after an exit was rejected — it may have been
+    /// closed or reduced outside the bot. Spot holdings can include coins
+    /// this strategy never bought, so only the fill-tracked amount counts.
+    fn sync_position(&mut self, ctx: &mut dyn StrategyContext) {
+        let actual = if self.market.is_spot() {
+            let base = &self.instrument_meta.as_ref().unwrap().base_asset;
+            ctx.balance(base).total.min(self.state.position_qty)
+        } else {
+            ctx.position(&self.instrument_id()).qty
+        };
+        if actual != self.state.position_qty {
+            ctx.log_warn(&format!(
+                "Position resynced: tracked {} → exchange {}",
+                self.state.position_qty, actual
+            ));
+            self.state.position_qty = actual;
+        }
+    }
+
+    /// Place a marketable IOC limit crossing the touch by up to
+    /// `max_slippage_pct`. Nothing rests: any unfilled remainder is canceled.
+    /// Returns false when there is no quote to price from.
+    fn place_taker(
+        &mut self,
+        ctx: &mut dyn StrategyContext,
+        side: OrderSide,
+        qty: Qty,
+        reduce_only: bool,
+    ) -> bool {
+        let (bid, ask) = match self.state.last_bid_ask {
+            Some(bid_ask) => bid_ask,
+            None => return false,
+        };
+        let raw = match side {
+            OrderSide::Buy => ask.0 * (Decimal::ONE + self.config.max_slippage_pct),
+            OrderSide::Sell => bid.0 * (Decimal::ONE - self.config.max_slippage_pct),
+        };
+        let price = self.round_price(Price::new(raw));
+        let mut order = PlaceOrder::limit(
+            self.exchange_instance(),
+            self.instrument_id(),
+            side,
+            price,
+            qty,
+        )
+        .with_tif(TimeInForce::Ioc);
+        if reduce_only && !self.market.is_spot() {
+            order = order.reduce_only();
+        }
+        self.state.active_order = Some(order.client_id.clone());
+        ctx.place_order(order);
+        ctx.log_info(&format!("{} IOC placed @ {} qty={}", side, price, qty));
+        true
+    }
+
+    /// Our active order reached a terminal state (completed, canceled or
+    /// rejected). IOC orders can end canceled after a partial fill, so the
+    /// outcome is judged from the tracked position, not the event type.
+    fn on_order_done(&mut self, ctx: &mut dyn StrategyContext, client_id: &ClientOrderId) {
+        if self.state.active_order.as_ref() != Some(client_id) {
+            return;
+        }
+        self.state.active_order = None;
+        match self.state.phase {
+            Phase::Opening => self.on_entry_done(ctx),
+            Phase::Closing => self.on_exit_done(ctx),
+            _ => {}
+        }
+    }
+
+    fn on_entry_done(&mut self, ctx: &mut dyn StrategyContext) {
+        if self.state.entry_qty.is_zero() {
+            ctx.log_warn("Entry not filled — back to watching");
+            self.state.phase = Phase::Watching;
+            return;
+        }
+        let entry = self.state.entry_notional / self.state.entry_qty;
+        let stop = entry * (Decimal::ONE - self.config.stop_loss_pct);
+        let target = entry * (Decimal::ONE + self.config.take_profit_pct);
+        self.state.stop_px = Some(self.round_price(Price::new(stop)));
+        self.state.target_px = Some(self.round_price(Price::new(target)));
+        self.state.phase = Phase::InPosition;
+        ctx.log_info(&format!(
+            "In position: qty={} entry={} stop={:?} target={:?}",
+            self.state.position_qty, entry, self.state.stop_px, self.state.target_px
+        ));
+    }
+
+    fn on_exit_done(&mut self, ctx: &mut dyn StrategyContext) {
+        if self.trunc_qty(Qty::new(self.state.position_qty)).is_zero() {
+            ctx.log_info("Position closed");
+            self.state.stop_px = None;
+            self.state.target_px = None;
+            self.state.phase = Phase::Watching;
+        } else {
+            // Stop/target stay armed — a later quote retries the exit,
+            // at most once per EXIT_RETRY_MS
+            ctx.log_warn(&format!(
+                "Exit incomplete, {} left",
+                self.state.position_qty
+            ));
+            self.state.phase = Phase::InPosition;
+        }
+    }
+}
+
+impl Strategy for MyStrategy {
+    fn id(&self) -> &StrategyId {
+        &self.config.strategy_id
+    }
+
+    fn on_start(&mut self, ctx: &mut dyn StrategyContext) {
+        // Load instrument metadata (tick size, lot size, etc.)
+        let instrument = self.instrument_id();
+        self.instrument_meta = ctx.instrument_meta(&instrument).cloned();
+
+        if self.instrument_meta.is_none() {
+            ctx.log_error(&format!("Instrument not found: {}", instrument));
+            ctx.stop_strategy(self.config.strategy_id.clone(), "Instrument not found");
+            return;
+        }
+
+        // Validate config
+        let errors = self.config.validate();
+        if !errors.is_empty() {
+            for err in &errors {
+                ctx.log_error(&format!("Config error: {}", err));
+            }
+            ctx.stop_strategy(
+                self.config.strategy_id.clone(),
+                &format!("Config validation failed: {}", errors.join("; ")),
+            );
+            return;
+        }
+
+        ctx.log_info(&format!(
+            "MyStrategy started: {} order_size={} bars={}s ema={} sl={} tp={}",
+            instrument,
+            self.config.order_size,
+            self.config.bar_interval_secs,
+            self.config.ema_period,
+            self.config.stop_loss_pct,
+            self.config.take_profit_pct
+        ));
+    }
+
+    fn on_event(&mut self, ctx: &mut dyn StrategyContext, event: &Event) {
+        if self.instrument_meta.is_none() {
+            return;
+        }
+
+        match event {
+            Event::Quote(q) => {
+                self.state.last_bid_ask = Some((q.bid, q.ask));
+                let mid = q.mid();
+
+                // Stops are checked on every quote, signals only on bar close
+                if self.state.phase == Phase::InPosition {
+                    self.check_exit(ctx, mid);
+                }
+                let mid_f64 = mid.0.to_f64().unwrap_or(0.0);
+                if let Some(bar) = self.bar_builder.update(mid_f64, q.ts) {
+                    self.on_bar(ctx, bar);
+                }
+            }
+            Event::OrderFilled(f) => {
+                ctx.log_info(&format!(
+                    "Filled: {} {} @ {} qty={}",
+                    f.side, f.client_id, f.price, f.qty
+                ));
+
+                // Spot BUY fees are taken in the base asset — track net_qty.
+                match f.side {
+                    OrderSide::Buy => {
+                        self.state.position_qty += f.net_qty.0;
+                        self.state.entry_notional += f.price.0 * f.qty.0;
+                        self.state.entry_qty += f.qty.0;
+                    }
+                    OrderSide::Sell => self.state.position_qty -= f.qty.0,
+                }
+            }
+            Event::OrderCompleted(c) => self.on_order_done(ctx, &c.client_id),
+            Event::OrderCanceled(c) => self.on_order_done(ctx, &c.client_id),
+            Event::OrderRejected(r) => {
+                ctx.log_warn(&format!("Rejected: {} reason={}", r.client_id, r.reason));
+                if self.state.phase == Phase::Closing
+                    && self.state.active_order.as_ref() == Some(&r.client_id)
+                {
+                    self.sync_position(ctx);
+                }
+                self.on_order_done(ctx, &r.client_id);
+            }
+            Event::ExchangeStateChanged(e) => {
+                ctx.log_info(&format!(
+                    "Exchange state: {:?} -> {:?} ({})",
+                    e.old_state, e.new_state, e.reason
+                ));
+                // TODO: Skip entries while Halted
+            }
+            _ => {}
+        }
+    }
+
+    fn on_timer(&mut self, _ctx: &mut dyn StrategyContext, _timer_id: TimerId) {
+        // TODO: Periodic logic — status logging, time-based exits, etc.
+    }
+
+    fn on_stop(&mut self, ctx: &mut dyn StrategyContext) {
+        // IOC orders never rest; this only catches one still in flight.
+        ctx.cancel_all(CancelAll::new(self.exchange_instance()));
+        if !self.state.position_qty.is_zero() {
+            ctx.log_warn(&format!(
+                "MyStrategy stopping with open position: {}",
+                self.state.position_qty
+            ));
+        } else {
+            ctx.log_info("MyStrategy stopping");
+        }
+    }
+}
@@ -30,8 +30,21 @@ pub struct MyConfig {
     /// Order size in base asset (e.g., 0.01 BTC)
     pub order_size: Decimal,
 
+    /// Maximum absolute position in base asset (0 = unlimited)
+    #[serde(default)]
+    pub max_position: Decimal,
+
+    /// Skip order placement while the bid/ask spread is wider than this,
+    /// in basis points of mid (0 = no limit)
+    #[serde(default)]
+    pub max_spread_bps: Decimal,
+
+    /// Cancel an order still resting this many seconds after placement
+    /// (0 = never)
+    #[serde(default)]
+    pub order_timeout_secs: u64,
+
     // pub spread_pct: Decimal,
-    // pub max_position: Decimal,
     // pub rebalance_interval_secs: u64,
 }
 
@@ -43,6 +56,12 @@ impl MyConfig {
         if self.order_size <= Decimal::ZERO {
             errors.push("order_size must be > 0".into());
         }
+        if self.max_position < Decimal::ZERO {
+            errors.push("max_position must be >= 0".into());
+        }
+        if self.max_spread_bps < Decimal::ZERO {
+            errors.push("max_spread_bps must be >= 0".into());
+        }
 
         // TODO: Add your validation rules here.
 
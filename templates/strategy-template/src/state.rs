@@ -3,7 +3,8 @@
 //! This struct tracks everything the strategy needs at runtime.
 //! Unlike config, this is NOT serialized — it's rebuilt on startup.
 
-use bot_core::{ClientOrderId, Price};
+use bot_core::{ClientOrderId, Price, TimerId};
+use rust_decimal::Decimal;
 
 /// Runtime state for MyStrategy.
 ///
@@ -18,6 +19,15 @@ pub struct MyState {
     /// Currently active order (if any)
     pub active_order: Option<ClientOrderId>,
 
+    /// Signed position from our own fills (positive = long, negative = short)
+    pub position_qty: Decimal,
+
+    /// Whether the last quote's spread was wider than `max_spread_bps`
+    pub spread_too_wide: bool,
+
+    /// One-shot timeout timers armed by `place_order`, and the order each guards
+    pub order_timeouts: Vec<(TimerId, ClientOrderId)>,
+
     /// Last periodic log timestamp
     pub last_log_ts: i64,
     // TODO: Add your state fields here.
@@ -33,6 +43,9 @@ impl MyState {
             is_initialized: false,
             last_mid: None,
             active_order: None,
+            position_qty: Decimal::ZERO,
+            spread_too_wide: false,
+            order_timeouts: Vec::new(),
             last_log_ts: 0,
         }
     }
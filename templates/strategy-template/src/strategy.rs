@@ -9,6 +9,8 @@
 use crate::config::MyConfig;
 use crate::state::MyState;
 use bot_core::*;
+use rust_decimal::Decimal;
+use std::time::Duration;
 
 /// MyStrategy trading strategy.
 ///
@@ -29,6 +31,7 @@ impl MyStrategy {
     }
 
     /// Round price to tick size and 5 significant figures.
+    #[allow(dead_code)] // TODO: Remove once your logic prices orders
     fn round_price(&self, price: Price) -> Price {
         let trimmed = price.trim_to_sig_figs(5);
         if let Some(ref meta) = self.instrument_meta {
@@ -39,6 +42,7 @@ impl MyStrategy {
     }
 
     /// Round quantity to lot size.
+    #[allow(dead_code)] // TODO: Remove once your logic sizes orders
     fn round_qty(&self, qty: Qty) -> Qty {
         if let Some(ref meta) = self.instrument_meta {
             meta.round_qty(qty)
@@ -47,6 +51,143 @@ impl MyStrategy {
         }
     }
 
+    /// Floor quantity to lot size. Use for orders that close a position
+    /// (sells of a long, buys back of a short) so they never exceed it.
+    #[allow(dead_code)] // TODO: Remove once your logic closes positions
+    fn trunc_qty(&self, qty: Qty) -> Qty {
+        if let Some(ref meta) = self.instrument_meta {
+            meta.trunc_qty(qty)
+        } else {
+            qty
+        }
+    }
+
+    /// Look up an order that can still fill. `ctx.order()` keeps returning
+    /// a `LiveOrder` in a terminal status until its event is processed.
+    fn open_order<'a>(
+        ctx: &'a dyn StrategyContext,
+        client_id: &ClientOrderId,
+    ) -> Option<&'a LiveOrder> {
+        ctx.order(client_id).filter(|o| {
+            matches!(
+                o.status,
+                OrderStatus::New | OrderStatus::Accepted | OrderStatus::PartiallyFilled
+            )
+        })
+    }
+
+    /// Whether an order would take the position beyond `max_position`.
+    ///
+    /// Reads the engine's position rather than `state.position_qty`, so fills
+    /// from before a restart and from other sessions are included. The
+    /// unfilled rest of a same-side `active_order` counts too, since it can
+    /// still fill. Other resting orders are not tracked by the template.
+    fn exceeds_max_position(&self, ctx: &dyn StrategyContext, side: OrderSide, qty: Qty) -> bool {
+        if self.config.max_position.is_zero() {
+            return false;
+        }
+        let mut pending = qty.0;
+        if let Some(order) = self
+            .state
+            .active_order
+            .as_ref()
+            .and_then(|id| Self::open_order(ctx, id))
+        {
+            if order.side == side {
+                pending += order.requested_qty.0 - order.filled_qty.0;
+            }
+        }
+        let current = ctx.position(&self.instrument_id()).qty;
+        let next = match side {
+            OrderSide::Buy => current + pending,
+            OrderSide::Sell => current - pending,
+        };
+        // Orders that shrink the position always pass, so a position already
+        // over the cap (restart, manual trade, lowered limit) can be reduced.
+        next.abs() > self.config.max_position && next.abs() > current.abs()
+    }
+
+    /// Whether the quoted spread exceeds `max_spread_bps`.
+    fn spread_too_wide(&self, bid: Price, ask: Price) -> bool {
+        if self.config.max_spread_bps.is_zero() {
+            return false;
+        }
+        let mid = (bid.0 + ask.0) / Decimal::TWO;
+        if mid <= Decimal::ZERO {
+            return true;
+        }
+        let spread_bps = (ask.0 - bid.0) / mid * Decimal::from(10_000);
+        spread_bps > self.config.max_spread_bps
+    }
+
+    /// Place an order and, if `order_timeout_secs` is set, arm a one-shot
+    /// timer that cancels it should it still be open when the timer fires.
+    ///
+    /// This is the single placement path, so the config guards live here:
+    /// the order is dropped and false is returned while the last quote was
+    /// wider than `max_spread_bps`, or if it would add to a position beyond
+    /// `max_position`.
+    #[allow(dead_code)] // TODO: Remove once your logic places orders through this
+    fn place_order(&mut self, ctx: &mut dyn StrategyContext, order: PlaceOrder) -> bool {
+        if self.state.spread_too_wide {
+            ctx.log_debug(&format!(
+                "Spread too wide — skipping {} @ {}",
+                order.side, order.price
+            ));
+            return false;
+        }
+        if self.exceeds_max_position(ctx, order.side, order.qty) {
+            ctx.log_warn(&format!(
+                "{} {} would exceed max_position {} — skipping",
+                order.side, order.qty, self.config.max_position
+            ));
+            return false;
+        }
+        let client_id = order.client_id.clone();
+        self.state.active_order = Some(client_id.clone());
+        ctx.place_order(order);
+        if self.config.order_timeout_secs > 0 {
+            let timer = ctx.set_timer(Duration::from_secs(self.config.order_timeout_secs));
+            self.state.order_timeouts.push((timer, client_id));
+        }
+        true
+    }
+
+    /// Drop the timeout for an order that reached a terminal state.
+    fn disarm_timeout(&mut self, ctx: &mut dyn StrategyContext, client_id: &ClientOrderId) {
+        if let Some(i) = self
+            .state
+            .order_timeouts
+            .iter()
+            .position(|(_, id)| id == client_id)
+        {
+            let (timer, _) = self.state.order_timeouts.remove(i);
+            ctx.cancel_timer(timer);
+        }
+    }
+
+    /// Handle an order timeout. Returns false if `timer_id` is not one.
+    fn on_order_timeout(&mut self, ctx: &mut dyn StrategyContext, timer_id: TimerId) -> bool {
+        let i = match self
+            .state
+            .order_timeouts
+            .iter()
+            .position(|(timer, _)| *timer == timer_id)
+        {
+            Some(i) => i,
+            None => return false,
+        };
+        let (_, client_id) = self.state.order_timeouts.remove(i);
+        if Self::open_order(ctx, &client_id).is_some() {
+            ctx.log_info(&format!(
+                "Order {} open for {}s — canceling",
+                client_id, self.config.order_timeout_secs
+            ));
+            ctx.cancel_order(CancelOrder::new(self.exchange_instance(), client_id));
+        }
+        true
+    }
+
     /// Get the exchange instance for order commands.
     fn exchange_instance(&self) -> ExchangeInstance {
         self.config
@@ -110,8 +251,32 @@ impl Strategy for MyStrategy {
                 let mid = q.mid();
                 self.state.last_mid = Some(mid);
 
+                // Spread guard: place_order() refuses orders while the book
+                // is too wide. Logged on transitions only, not on every quote.
+                let too_wide = self.spread_too_wide(q.bid, q.ask);
+                if too_wide != self.state.spread_too_wide {
+                    if too_wide {
+                        ctx.log_warn(&format!(
+                            "Spread too wide ({} / {}), pausing orders (max {} bps)",
+                            q.bid, q.ask, self.config.max_spread_bps
+                        ));
+                    } else {
+                        ctx.log_info("Spread back within limit, resuming orders");
+                    }
+                    self.state.spread_too_wide = too_wide;
+                }
+
                 // TODO: Your price-reactive trading logic here.
-                // Example: Check spread, place orders, rebalance grid, etc.
+                // Example: Place orders, rebalance grid, etc.
+                // Place through self.place_order() — from here, fills or timers —
+                // so max_spread_bps, max_position and order_timeout_secs apply.
+            }
+            Event::OrderAccepted(a) => {
+                // Log the exchange oid so orders can be found in the HL UI/explorer
+                ctx.log_info(&format!(
+                    "Accepted: {} oid={:?}",
+                    a.client_id, a.exchange_order_id
+                ));
             }
             Event::OrderFilled(f) => {
                 ctx.log_info(&format!(
@@ -119,7 +284,15 @@ impl Strategy for MyStrategy {
                     f.side, f.client_id, f.price, f.qty
                 ));
 
+                // Spot BUY fees are taken in the base asset — track net_qty.
+                match f.side {
+                    OrderSide::Buy => self.state.position_qty += f.net_qty.0,
+                    OrderSide::Sell => self.state.position_qty -= f.qty.0,
+                }
+
                 // TODO: Handle fill — update state, place counter-order, etc.
+                // Closing orders: size with self.trunc_qty(), and on perps
+                // add .reduce_only() so a short cover never flips to long.
             }
             Event::OrderCompleted(c) => {
                 ctx.log_info(&format!(
@@ -129,16 +302,19 @@ impl Strategy for MyStrategy {
 
                 // TODO: Order fully filled — cycle logic, place next order, etc.
                 self.state.active_order = None;
+                self.disarm_timeout(ctx, &c.client_id);
             }
             Event::OrderCanceled(c) => {
                 ctx.log_info(&format!("Canceled: {}", c.client_id));
                 self.state.active_order = None;
+                self.disarm_timeout(ctx, &c.client_id);
 
                 // TODO: Handle cancel — retry, reset level, etc.
             }
             Event::OrderRejected(r) => {
                 ctx.log_warn(&format!("Rejected: {} reason={}", r.client_id, r.reason));
                 self.state.active_order = None;
+                self.disarm_timeout(ctx, &r.client_id);
 
                 // TODO: Handle rejection — adjust price/qty, log error, etc.
             }
@@ -153,7 +329,11 @@ impl Strategy for MyStrategy {
         }
     }
 
-    fn on_timer(&mut self, ctx: &mut dyn StrategyContext, _timer_id: TimerId) {
+    fn on_timer(&mut self, ctx: &mut dyn StrategyContext, timer_id: TimerId) {
+        if self.on_order_timeout(ctx, timer_id) {
+            return;
+        }
+
         // TODO: Periodic logic — status logging, rebalancing, health checks, etc.
 
         // Example: periodic status log
@@ -161,8 +341,8 @@ impl Strategy for MyStrategy {
         if now - self.state.last_log_ts > 30_000 {
             if let Some(mid) = self.state.last_mid {
                 ctx.log_info(&format!(
-                    "Status: mid={} active_order={:?}",
-                    mid, self.state.active_order
+                    "Status: mid={} position={} active_order={:?}",
+                    mid, self.state.position_qty, self.state.active_order
                 ));
             }
             self.state.last_log_ts = now;
@@ -172,5 +352,11 @@ impl Strategy for MyStrategy {
     fn on_stop(&mut self, ctx: &mut dyn StrategyContext) {
         ctx.log_info("MyStrategy stopping — canceling all orders");
         ctx.cancel_all(CancelAll::new(self.exchange_instance()));
+        if !self.state.position_qty.is_zero() {
+            ctx.log_warn(&format!(
+                "MyStrategy stopping with open position: {}",
+                self.state.position_qty
+            ));
+        }
     }
 }